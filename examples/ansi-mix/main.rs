@@ -25,16 +25,17 @@ fn main() {
     const T1: &str = "HSL walk";
     const T2: &str = "RGB walk";
     const T3: &str = "blend";
+    const T4: &str = "OKLab blend";
     let b = bar(N + 2);
     let b = &b;
     println!(
         "\n{:^w$}",
         "Blending random ANSI colors, all variants still ANSI",
-        w = 3 * N + 18
+        w = 4 * N + 21
     );
-    println!(" ┌─────┬─────┬{}┬{}┬{}┐", b, b, b);
-    println!(" │ src │ dst │{:^w$}│{:^w$}│{:^w$}│", T1, T2, T3, w = N + 2);
-    println!(" ├─────┼─────┼{}┼{}┼{}┤", b, b, b);
+    println!(" ┌─────┬─────┬{}┬{}┬{}┬{}┐", b, b, b, b);
+    println!(" │ src │ dst │{:^w$}│{:^w$}│{:^w$}│{:^w$}│", T1, T2, T3, T4, w = N + 2);
+    println!(" ├─────┼─────┼{}┼{}┼{}┼{}┤", b, b, b, b);
     for _ in 0..20 {
         let c1 = rand_ansi();
         let c2 = rand_ansi();
@@ -67,7 +68,13 @@ fn main() {
             print_ansi(c.ansi());
         }
         print!(" │ ");
+        let stops = [ColorStop::new(0.0, c1.into()), ColorStop::new(1.0, c2.into())];
+        let gradient = Gradient::new(&stops, InterpolationSpace::Oklab).unwrap();
+        for c in gradient.iter(N) {
+            print_ansi(c.ansi());
+        }
+        print!(" │ ");
         println!();
     }
-    println!(" └─────┴─────┴{}┴{}┴{}┘\n", b, b, b);
+    println!(" └─────┴─────┴{}┴{}┴{}┴{}┘\n", b, b, b, b);
 }
@@ -19,11 +19,18 @@ impl AnsiColor {
     pub const fn to_rgb(self) -> Rgb {
         ANSI_TO_RGB[self.code as usize]
     }
+    /// Return the RGB color displayed by a terminal using the given palette
+    #[inline(always)]
+    pub const fn to_rgb_in(self, palette: &Palette) -> Rgb {
+        palette.rgb(self)
+    }
     pub fn with_luminosity_change(self, delta_luminosity: f32) -> Self {
         let mut hsl = self.to_hsl();
         hsl.l = (hsl.l + delta_luminosity).clamp(0.0, 1.0);
         hsl.to_ansi()
     }
+    /// Return the ANSI color nearest to this one with the given HSL
+    /// lightness (see [TonalScale] for a whole ramp of tones)
     pub fn with_luminosity(self, l: f32) -> Self {
         let mut hsl = self.to_hsl();
         hsl.l = l;
@@ -1333,77 +1340,77 @@ pub const ANSI_TO_HSL: &[Hsl] = &[
     Hsl {
         h: 0.0,
         s: 1.0,
-        l: 0.25,
+        l: 0.2509804,
     },
     Hsl {
         h: 120.0,
         s: 1.0,
-        l: 0.25,
+        l: 0.2509804,
     },
     Hsl {
         h: 60.0,
         s: 1.0,
-        l: 0.25,
+        l: 0.2509804,
     },
     Hsl {
         h: 240.0,
         s: 1.0,
-        l: 0.25,
+        l: 0.2509804,
     },
     Hsl {
         h: 300.0,
         s: 1.0,
-        l: 0.25,
+        l: 0.2509804,
     },
     Hsl {
         h: 180.0,
         s: 1.0,
-        l: 0.25,
+        l: 0.2509804,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.75,
+        l: 0.7529412,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.5,
+        l: 0.5019608,
     },
     Hsl {
         h: 0.0,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 120.0,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 60.0,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 240.0,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 300.0,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 180.0,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.99609375,
+        l: 1.0,
     },
     Hsl {
         h: 0.0,
@@ -1413,1197 +1420,1197 @@ pub const ANSI_TO_HSL: &[Hsl] = &[
     Hsl {
         h: 240.0,
         s: 1.0,
-        l: 0.18554688,
+        l: 0.18627451,
     },
     Hsl {
         h: 240.0,
         s: 1.0,
-        l: 0.26367188,
+        l: 0.2647059,
     },
     Hsl {
         h: 240.0,
         s: 1.0,
-        l: 0.34179688,
+        l: 0.34313726,
     },
     Hsl {
         h: 240.0,
         s: 1.0,
-        l: 0.41992188,
+        l: 0.42156863,
     },
     Hsl {
         h: 240.0,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 120.0,
         s: 1.0,
-        l: 0.18554688,
+        l: 0.18627451,
     },
     Hsl {
         h: 180.0,
         s: 1.0,
-        l: 0.18554688,
+        l: 0.18627451,
     },
     Hsl {
         h: 197.77777,
         s: 1.0,
-        l: 0.26367188,
+        l: 0.2647059,
     },
     Hsl {
         h: 207.42859,
         s: 1.0,
-        l: 0.34179688,
+        l: 0.34313726,
     },
     Hsl {
         h: 213.4884,
         s: 1.0,
-        l: 0.41992188,
+        l: 0.42156863,
     },
     Hsl {
         h: 217.6471,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 120.0,
         s: 1.0,
-        l: 0.26367188,
+        l: 0.2647059,
     },
     Hsl {
         h: 162.22223,
         s: 1.0,
-        l: 0.26367188,
+        l: 0.2647059,
     },
     Hsl {
         h: 180.0,
         s: 1.0,
-        l: 0.26367188,
+        l: 0.2647059,
     },
     Hsl {
         h: 193.7143,
         s: 1.0,
-        l: 0.34179688,
+        l: 0.34313726,
     },
     Hsl {
         h: 202.32556,
         s: 1.0,
-        l: 0.41992188,
+        l: 0.42156863,
     },
     Hsl {
         h: 208.23529,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 120.0,
         s: 1.0,
-        l: 0.34179688,
+        l: 0.34313726,
     },
     Hsl {
         h: 152.57141,
         s: 1.0,
-        l: 0.34179688,
+        l: 0.34313726,
     },
     Hsl {
         h: 166.2857,
         s: 1.0,
-        l: 0.34179688,
+        l: 0.34313726,
     },
     Hsl {
         h: 180.0,
         s: 1.0,
-        l: 0.34179688,
+        l: 0.34313726,
     },
     Hsl {
         h: 191.16278,
         s: 1.0,
-        l: 0.41992188,
+        l: 0.42156863,
     },
     Hsl {
         h: 198.82355,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 120.0,
         s: 1.0,
-        l: 0.41992188,
+        l: 0.42156863,
     },
     Hsl {
         h: 146.51163,
         s: 1.0,
-        l: 0.41992188,
+        l: 0.42156863,
     },
     Hsl {
         h: 157.67444,
         s: 1.0,
-        l: 0.41992188,
+        l: 0.42156863,
     },
     Hsl {
         h: 168.83722,
         s: 1.0,
-        l: 0.41992188,
+        l: 0.42156863,
     },
     Hsl {
         h: 180.0,
         s: 1.0,
-        l: 0.41992188,
+        l: 0.42156863,
     },
     Hsl {
         h: 189.41174,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 120.0,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 142.35294,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 151.76471,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 161.17645,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 170.58826,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 180.0,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 0.0,
         s: 1.0,
-        l: 0.18554688,
+        l: 0.18627451,
     },
     Hsl {
         h: 300.0,
         s: 1.0,
-        l: 0.18554688,
+        l: 0.18627451,
     },
     Hsl {
         h: 282.22223,
         s: 1.0,
-        l: 0.26367188,
+        l: 0.2647059,
     },
     Hsl {
         h: 272.5714,
         s: 1.0,
-        l: 0.34179688,
+        l: 0.34313726,
     },
     Hsl {
         h: 266.5116,
         s: 1.0,
-        l: 0.41992188,
+        l: 0.42156863,
     },
     Hsl {
         h: 262.3529,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 60.0,
         s: 1.0,
-        l: 0.18554688,
+        l: 0.18627451,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.37109375,
+        l: 0.37254903,
     },
     Hsl {
         h: 240.0,
         s: 0.17391305,
-        l: 0.44921875,
+        l: 0.45098042,
     },
     Hsl {
         h: 240.0,
-        s: 0.3305785,
-        l: 0.52734375,
+        s: 0.33333337,
+        l: 0.5294118,
     },
     Hsl {
         h: 240.0,
-        s: 0.5940594,
-        l: 0.60546875,
+        s: 0.6,
+        l: 0.60784316,
     },
     Hsl {
         h: 240.0,
-        s: 0.9876543,
-        l: 0.68359375,
+        s: 1.0,
+        l: 0.6862745,
     },
     Hsl {
         h: 77.77777,
         s: 1.0,
-        l: 0.26367188,
+        l: 0.2647059,
     },
     Hsl {
         h: 120.0,
         s: 0.17391305,
-        l: 0.44921875,
+        l: 0.45098042,
     },
     Hsl {
         h: 180.0,
         s: 0.17391305,
-        l: 0.44921875,
+        l: 0.45098042,
     },
     Hsl {
         h: 210.0,
-        s: 0.3305785,
-        l: 0.52734375,
+        s: 0.33333337,
+        l: 0.5294118,
     },
     Hsl {
         h: 220.0,
-        s: 0.5940594,
-        l: 0.60546875,
+        s: 0.6,
+        l: 0.60784316,
     },
     Hsl {
         h: 225.0,
-        s: 0.9876543,
-        l: 0.68359375,
+        s: 1.0,
+        l: 0.6862745,
     },
     Hsl {
         h: 87.42859,
         s: 1.0,
-        l: 0.34179688,
+        l: 0.34313726,
     },
     Hsl {
         h: 120.0,
-        s: 0.3305785,
-        l: 0.52734375,
+        s: 0.33333337,
+        l: 0.5294118,
     },
     Hsl {
         h: 150.0,
-        s: 0.3305785,
-        l: 0.52734375,
+        s: 0.33333337,
+        l: 0.5294118,
     },
     Hsl {
         h: 180.0,
-        s: 0.3305785,
-        l: 0.52734375,
+        s: 0.33333337,
+        l: 0.5294118,
     },
     Hsl {
         h: 200.0,
-        s: 0.5940594,
-        l: 0.60546875,
+        s: 0.6,
+        l: 0.60784316,
     },
     Hsl {
         h: 210.0,
-        s: 0.9876543,
-        l: 0.68359375,
+        s: 1.0,
+        l: 0.6862745,
     },
     Hsl {
         h: 93.48837,
         s: 1.0,
-        l: 0.41992188,
+        l: 0.42156863,
     },
     Hsl {
         h: 120.0,
-        s: 0.5940594,
-        l: 0.60546875,
+        s: 0.6,
+        l: 0.60784316,
     },
     Hsl {
         h: 140.0,
-        s: 0.5940594,
-        l: 0.60546875,
+        s: 0.6,
+        l: 0.60784316,
     },
     Hsl {
         h: 160.0,
-        s: 0.5940594,
-        l: 0.60546875,
+        s: 0.6,
+        l: 0.60784316,
     },
     Hsl {
         h: 180.0,
-        s: 0.5940594,
-        l: 0.60546875,
+        s: 0.6,
+        l: 0.60784316,
     },
     Hsl {
         h: 195.0,
-        s: 0.9876543,
-        l: 0.68359375,
+        s: 1.0,
+        l: 0.6862745,
     },
     Hsl {
         h: 97.647064,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 120.0,
-        s: 0.9876543,
-        l: 0.68359375,
+        s: 1.0,
+        l: 0.6862745,
     },
     Hsl {
         h: 135.0,
-        s: 0.9876543,
-        l: 0.68359375,
+        s: 1.0,
+        l: 0.6862745,
     },
     Hsl {
         h: 150.0,
-        s: 0.9876543,
-        l: 0.68359375,
+        s: 1.0,
+        l: 0.6862745,
     },
     Hsl {
         h: 165.0,
-        s: 0.9876543,
-        l: 0.68359375,
+        s: 1.0,
+        l: 0.6862745,
     },
     Hsl {
         h: 180.0,
-        s: 0.9876543,
-        l: 0.68359375,
+        s: 1.0,
+        l: 0.6862745,
     },
     Hsl {
         h: 0.0,
         s: 1.0,
-        l: 0.26367188,
+        l: 0.2647059,
     },
     Hsl {
         h: 317.77777,
         s: 1.0,
-        l: 0.26367188,
+        l: 0.2647059,
     },
     Hsl {
         h: 300.0,
         s: 1.0,
-        l: 0.26367188,
+        l: 0.2647059,
     },
     Hsl {
         h: 286.2857,
         s: 1.0,
-        l: 0.34179688,
+        l: 0.34313726,
     },
     Hsl {
         h: 277.67444,
         s: 1.0,
-        l: 0.41992188,
+        l: 0.42156863,
     },
     Hsl {
         h: 271.7647,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 42.22223,
         s: 1.0,
-        l: 0.26367188,
+        l: 0.2647059,
     },
     Hsl {
         h: 0.0,
         s: 0.17391305,
-        l: 0.44921875,
+        l: 0.45098042,
     },
     Hsl {
         h: 300.0,
         s: 0.17391305,
-        l: 0.44921875,
+        l: 0.45098042,
     },
     Hsl {
         h: 270.0,
-        s: 0.3305785,
-        l: 0.52734375,
+        s: 0.33333337,
+        l: 0.5294118,
     },
     Hsl {
         h: 260.0,
-        s: 0.5940594,
-        l: 0.60546875,
+        s: 0.6,
+        l: 0.60784316,
     },
     Hsl {
         h: 255.0,
-        s: 0.9876543,
-        l: 0.68359375,
+        s: 1.0,
+        l: 0.6862745,
     },
     Hsl {
         h: 60.0,
         s: 1.0,
-        l: 0.26367188,
+        l: 0.2647059,
     },
     Hsl {
         h: 60.0,
         s: 0.17391305,
-        l: 0.44921875,
+        l: 0.45098042,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.52734375,
+        l: 0.5294118,
     },
     Hsl {
         h: 240.0,
-        s: 0.1980198,
-        l: 0.60546875,
+        s: 0.2,
+        l: 0.60784316,
     },
     Hsl {
         h: 240.0,
-        s: 0.49382716,
-        l: 0.68359375,
+        s: 0.5,
+        l: 0.6862745,
     },
     Hsl {
         h: 240.0,
-        s: 0.9836066,
-        l: 0.76171875,
+        s: 1.0,
+        l: 0.7647059,
     },
     Hsl {
         h: 73.714294,
         s: 1.0,
-        l: 0.34179688,
+        l: 0.34313726,
     },
     Hsl {
         h: 90.0,
-        s: 0.3305785,
-        l: 0.52734375,
+        s: 0.33333337,
+        l: 0.5294118,
     },
     Hsl {
         h: 120.0,
-        s: 0.1980198,
-        l: 0.60546875,
+        s: 0.2,
+        l: 0.60784316,
     },
     Hsl {
         h: 180.0,
-        s: 0.1980198,
-        l: 0.60546875,
+        s: 0.2,
+        l: 0.60784316,
     },
     Hsl {
         h: 210.0,
-        s: 0.49382716,
-        l: 0.68359375,
+        s: 0.5,
+        l: 0.6862745,
     },
     Hsl {
         h: 220.0,
-        s: 0.9836066,
-        l: 0.76171875,
+        s: 1.0,
+        l: 0.7647059,
     },
     Hsl {
         h: 82.32556,
         s: 1.0,
-        l: 0.41992188,
+        l: 0.42156863,
     },
     Hsl {
         h: 100.0,
-        s: 0.5940594,
-        l: 0.60546875,
+        s: 0.6,
+        l: 0.60784316,
     },
     Hsl {
         h: 120.0,
-        s: 0.49382716,
-        l: 0.68359375,
+        s: 0.5,
+        l: 0.6862745,
     },
     Hsl {
         h: 150.0,
-        s: 0.49382716,
-        l: 0.68359375,
+        s: 0.5,
+        l: 0.6862745,
     },
     Hsl {
         h: 180.0,
-        s: 0.49382716,
-        l: 0.68359375,
+        s: 0.5,
+        l: 0.6862745,
     },
     Hsl {
         h: 200.0,
-        s: 0.9836066,
-        l: 0.76171875,
+        s: 1.0,
+        l: 0.7647059,
     },
     Hsl {
         h: 88.23529,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 105.0,
-        s: 0.9876543,
-        l: 0.68359375,
+        s: 1.0,
+        l: 0.6862745,
     },
     Hsl {
         h: 120.0,
-        s: 0.9836066,
-        l: 0.76171875,
+        s: 1.0,
+        l: 0.7647059,
     },
     Hsl {
         h: 140.0,
-        s: 0.9836066,
-        l: 0.76171875,
+        s: 1.0,
+        l: 0.7647059,
     },
     Hsl {
         h: 160.0,
-        s: 0.9836066,
-        l: 0.76171875,
+        s: 1.0,
+        l: 0.7647059,
     },
     Hsl {
         h: 180.0,
-        s: 0.9836066,
-        l: 0.76171875,
+        s: 1.0,
+        l: 0.7647059,
     },
     Hsl {
         h: 0.0,
         s: 1.0,
-        l: 0.34179688,
+        l: 0.34313726,
     },
     Hsl {
-        h: 327.42856,
+        h: 327.4286,
         s: 1.0,
-        l: 0.34179688,
+        l: 0.34313726,
     },
     Hsl {
         h: 313.7143,
         s: 1.0,
-        l: 0.34179688,
+        l: 0.34313726,
     },
     Hsl {
         h: 300.0,
         s: 1.0,
-        l: 0.34179688,
+        l: 0.34313726,
     },
     Hsl {
         h: 288.83722,
         s: 1.0,
-        l: 0.41992188,
+        l: 0.42156863,
     },
     Hsl {
         h: 281.1765,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
-        h: 32.57144,
+        h: 32.57141,
         s: 1.0,
-        l: 0.34179688,
+        l: 0.34313726,
     },
     Hsl {
         h: 0.0,
-        s: 0.3305785,
-        l: 0.52734375,
+        s: 0.33333337,
+        l: 0.5294118,
     },
     Hsl {
         h: 330.0,
-        s: 0.3305785,
-        l: 0.52734375,
+        s: 0.33333337,
+        l: 0.5294118,
     },
     Hsl {
         h: 300.0,
-        s: 0.3305785,
-        l: 0.52734375,
+        s: 0.33333337,
+        l: 0.5294118,
     },
     Hsl {
         h: 280.0,
-        s: 0.5940594,
-        l: 0.60546875,
+        s: 0.6,
+        l: 0.60784316,
     },
     Hsl {
         h: 270.0,
-        s: 0.9876543,
-        l: 0.68359375,
+        s: 1.0,
+        l: 0.6862745,
     },
     Hsl {
         h: 46.285706,
         s: 1.0,
-        l: 0.34179688,
+        l: 0.34313726,
     },
     Hsl {
         h: 30.0,
-        s: 0.3305785,
-        l: 0.52734375,
+        s: 0.33333337,
+        l: 0.5294118,
     },
     Hsl {
         h: 0.0,
-        s: 0.1980198,
-        l: 0.60546875,
+        s: 0.2,
+        l: 0.60784316,
     },
     Hsl {
         h: 300.0,
-        s: 0.1980198,
-        l: 0.60546875,
+        s: 0.2,
+        l: 0.60784316,
     },
     Hsl {
         h: 270.0,
-        s: 0.49382716,
-        l: 0.68359375,
+        s: 0.5,
+        l: 0.6862745,
     },
     Hsl {
         h: 260.0,
-        s: 0.9836066,
-        l: 0.76171875,
+        s: 1.0,
+        l: 0.7647059,
     },
     Hsl {
         h: 60.0,
         s: 1.0,
-        l: 0.34179688,
+        l: 0.34313726,
     },
     Hsl {
         h: 60.0,
-        s: 0.3305785,
-        l: 0.52734375,
+        s: 0.33333337,
+        l: 0.5294118,
     },
     Hsl {
         h: 60.0,
-        s: 0.1980198,
-        l: 0.60546875,
+        s: 0.2,
+        l: 0.60784316,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.68359375,
+        l: 0.6862745,
     },
     Hsl {
         h: 240.0,
-        s: 0.32786885,
-        l: 0.76171875,
+        s: 0.33333334,
+        l: 0.7647059,
     },
     Hsl {
         h: 240.0,
-        s: 0.9756098,
-        l: 0.83984375,
+        s: 1.0,
+        l: 0.84313726,
     },
     Hsl {
         h: 71.16278,
         s: 1.0,
-        l: 0.41992188,
+        l: 0.42156863,
     },
     Hsl {
         h: 80.0,
-        s: 0.5940594,
-        l: 0.60546875,
+        s: 0.6,
+        l: 0.60784316,
     },
     Hsl {
         h: 90.0,
-        s: 0.49382716,
-        l: 0.68359375,
+        s: 0.5,
+        l: 0.6862745,
     },
     Hsl {
         h: 120.0,
-        s: 0.32786885,
-        l: 0.76171875,
+        s: 0.33333334,
+        l: 0.7647059,
     },
     Hsl {
         h: 180.0,
-        s: 0.32786885,
-        l: 0.76171875,
+        s: 0.33333334,
+        l: 0.7647059,
     },
     Hsl {
         h: 210.0,
-        s: 0.9756098,
-        l: 0.83984375,
+        s: 1.0,
+        l: 0.84313726,
     },
     Hsl {
         h: 78.82355,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 90.0,
-        s: 0.9876543,
-        l: 0.68359375,
+        s: 1.0,
+        l: 0.6862745,
     },
     Hsl {
         h: 100.0,
-        s: 0.9836066,
-        l: 0.76171875,
+        s: 1.0,
+        l: 0.7647059,
     },
     Hsl {
         h: 120.0,
-        s: 0.9756098,
-        l: 0.83984375,
+        s: 1.0,
+        l: 0.84313726,
     },
     Hsl {
         h: 150.0,
-        s: 0.9756098,
-        l: 0.83984375,
+        s: 1.0,
+        l: 0.84313726,
     },
     Hsl {
         h: 180.0,
-        s: 0.9756098,
-        l: 0.83984375,
+        s: 1.0,
+        l: 0.84313726,
     },
     Hsl {
         h: 0.0,
         s: 1.0,
-        l: 0.41992188,
+        l: 0.42156863,
     },
     Hsl {
         h: 333.48837,
         s: 1.0,
-        l: 0.41992188,
+        l: 0.42156863,
     },
     Hsl {
         h: 322.3256,
         s: 1.0,
-        l: 0.41992188,
+        l: 0.42156863,
     },
     Hsl {
         h: 311.16278,
         s: 1.0,
-        l: 0.41992188,
+        l: 0.42156863,
     },
     Hsl {
         h: 300.0,
         s: 1.0,
-        l: 0.41992188,
+        l: 0.42156863,
     },
     Hsl {
         h: 290.58826,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 26.511627,
         s: 1.0,
-        l: 0.41992188,
+        l: 0.42156863,
     },
     Hsl {
         h: 0.0,
-        s: 0.5940594,
-        l: 0.60546875,
+        s: 0.6,
+        l: 0.60784316,
     },
     Hsl {
         h: 340.0,
-        s: 0.5940594,
-        l: 0.60546875,
+        s: 0.6,
+        l: 0.60784316,
     },
     Hsl {
         h: 320.0,
-        s: 0.5940594,
-        l: 0.60546875,
+        s: 0.6,
+        l: 0.60784316,
     },
     Hsl {
         h: 300.0,
-        s: 0.5940594,
-        l: 0.60546875,
+        s: 0.6,
+        l: 0.60784316,
     },
     Hsl {
         h: 285.0,
-        s: 0.9876543,
-        l: 0.68359375,
+        s: 1.0,
+        l: 0.6862745,
     },
     Hsl {
         h: 37.674408,
         s: 1.0,
-        l: 0.41992188,
+        l: 0.42156863,
     },
     Hsl {
         h: 20.0,
-        s: 0.5940594,
-        l: 0.60546875,
+        s: 0.6,
+        l: 0.60784316,
     },
     Hsl {
         h: 0.0,
-        s: 0.49382716,
-        l: 0.68359375,
+        s: 0.5,
+        l: 0.6862745,
     },
     Hsl {
         h: 330.0,
-        s: 0.49382716,
-        l: 0.68359375,
+        s: 0.5,
+        l: 0.6862745,
     },
     Hsl {
         h: 300.0,
-        s: 0.49382716,
-        l: 0.68359375,
+        s: 0.5,
+        l: 0.6862745,
     },
     Hsl {
         h: 280.0,
-        s: 0.9836066,
-        l: 0.76171875,
+        s: 1.0,
+        l: 0.7647059,
     },
     Hsl {
         h: 48.83722,
         s: 1.0,
-        l: 0.41992188,
+        l: 0.42156863,
     },
     Hsl {
         h: 40.0,
-        s: 0.5940594,
-        l: 0.60546875,
+        s: 0.6,
+        l: 0.60784316,
     },
     Hsl {
         h: 30.0,
-        s: 0.49382716,
-        l: 0.68359375,
+        s: 0.5,
+        l: 0.6862745,
     },
     Hsl {
         h: 0.0,
-        s: 0.32786885,
-        l: 0.76171875,
+        s: 0.33333334,
+        l: 0.7647059,
     },
     Hsl {
         h: 300.0,
-        s: 0.32786885,
-        l: 0.76171875,
+        s: 0.33333334,
+        l: 0.7647059,
     },
     Hsl {
         h: 270.0,
-        s: 0.9756098,
-        l: 0.83984375,
+        s: 1.0,
+        l: 0.84313726,
     },
     Hsl {
         h: 60.0,
         s: 1.0,
-        l: 0.41992188,
+        l: 0.42156863,
     },
     Hsl {
         h: 60.0,
-        s: 0.5940594,
-        l: 0.60546875,
+        s: 0.6,
+        l: 0.60784316,
     },
     Hsl {
         h: 60.0,
-        s: 0.49382716,
-        l: 0.68359375,
+        s: 0.5,
+        l: 0.6862745,
     },
     Hsl {
         h: 60.0,
-        s: 0.32786885,
-        l: 0.76171875,
+        s: 0.33333334,
+        l: 0.7647059,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.83984375,
+        l: 0.84313726,
     },
     Hsl {
         h: 240.0,
-        s: 0.95238096,
-        l: 0.91796875,
+        s: 1.0,
+        l: 0.92156863,
     },
     Hsl {
         h: 69.41177,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 75.0,
-        s: 0.9876543,
-        l: 0.68359375,
+        s: 1.0,
+        l: 0.6862745,
     },
     Hsl {
         h: 80.0,
-        s: 0.9836066,
-        l: 0.76171875,
+        s: 1.0,
+        l: 0.7647059,
     },
     Hsl {
         h: 90.0,
-        s: 0.9756098,
-        l: 0.83984375,
+        s: 1.0,
+        l: 0.84313726,
     },
     Hsl {
         h: 120.0,
-        s: 0.95238096,
-        l: 0.91796875,
+        s: 1.0,
+        l: 0.92156863,
     },
     Hsl {
         h: 180.0,
-        s: 0.95238096,
-        l: 0.91796875,
+        s: 1.0,
+        l: 0.92156863,
     },
     Hsl {
         h: 0.0,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 337.64706,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 328.2353,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 318.82352,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 309.41177,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 300.0,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 22.352936,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 0.0,
-        s: 0.9876543,
-        l: 0.68359375,
+        s: 1.0,
+        l: 0.6862745,
     },
     Hsl {
         h: 345.0,
-        s: 0.9876543,
-        l: 0.68359375,
+        s: 1.0,
+        l: 0.6862745,
     },
     Hsl {
         h: 330.0,
-        s: 0.9876543,
-        l: 0.68359375,
+        s: 1.0,
+        l: 0.6862745,
     },
     Hsl {
         h: 315.0,
-        s: 0.9876543,
-        l: 0.68359375,
+        s: 1.0,
+        l: 0.6862745,
     },
     Hsl {
         h: 300.0,
-        s: 0.9876543,
-        l: 0.68359375,
+        s: 1.0,
+        l: 0.6862745,
     },
     Hsl {
         h: 31.76471,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 15.0,
-        s: 0.9876543,
-        l: 0.68359375,
+        s: 1.0,
+        l: 0.6862745,
     },
     Hsl {
         h: 0.0,
-        s: 0.9836066,
-        l: 0.76171875,
+        s: 1.0,
+        l: 0.7647059,
     },
     Hsl {
         h: 340.0,
-        s: 0.9836066,
-        l: 0.76171875,
+        s: 1.0,
+        l: 0.7647059,
     },
     Hsl {
         h: 320.0,
-        s: 0.9836066,
-        l: 0.76171875,
+        s: 1.0,
+        l: 0.7647059,
     },
     Hsl {
         h: 300.0,
-        s: 0.9836066,
-        l: 0.76171875,
+        s: 1.0,
+        l: 0.7647059,
     },
     Hsl {
         h: 41.176483,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 30.0,
-        s: 0.9876543,
-        l: 0.68359375,
+        s: 1.0,
+        l: 0.6862745,
     },
     Hsl {
         h: 20.0,
-        s: 0.9836066,
-        l: 0.76171875,
+        s: 1.0,
+        l: 0.7647059,
     },
     Hsl {
         h: 0.0,
-        s: 0.9756098,
-        l: 0.83984375,
+        s: 1.0,
+        l: 0.84313726,
     },
     Hsl {
         h: 330.0,
-        s: 0.9756098,
-        l: 0.83984375,
+        s: 1.0,
+        l: 0.84313726,
     },
     Hsl {
         h: 300.0,
-        s: 0.9756098,
-        l: 0.83984375,
+        s: 1.0,
+        l: 0.84313726,
     },
     Hsl {
         h: 50.588226,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 45.0,
-        s: 0.9876543,
-        l: 0.68359375,
+        s: 1.0,
+        l: 0.6862745,
     },
     Hsl {
         h: 40.0,
-        s: 0.9836066,
-        l: 0.76171875,
+        s: 1.0,
+        l: 0.7647059,
     },
     Hsl {
         h: 30.0,
-        s: 0.9756098,
-        l: 0.83984375,
+        s: 1.0,
+        l: 0.84313726,
     },
     Hsl {
         h: 0.0,
-        s: 0.95238096,
-        l: 0.91796875,
+        s: 1.0,
+        l: 0.92156863,
     },
     Hsl {
         h: 300.0,
-        s: 0.95238096,
-        l: 0.91796875,
+        s: 1.0,
+        l: 0.92156863,
     },
     Hsl {
         h: 60.0,
         s: 1.0,
-        l: 0.49804688,
+        l: 0.5,
     },
     Hsl {
         h: 60.0,
-        s: 0.9876543,
-        l: 0.68359375,
+        s: 1.0,
+        l: 0.6862745,
     },
     Hsl {
         h: 60.0,
-        s: 0.9836066,
-        l: 0.76171875,
+        s: 1.0,
+        l: 0.7647059,
     },
     Hsl {
         h: 60.0,
-        s: 0.9756098,
-        l: 0.83984375,
+        s: 1.0,
+        l: 0.84313726,
     },
     Hsl {
         h: 60.0,
-        s: 0.95238096,
-        l: 0.91796875,
+        s: 1.0,
+        l: 0.92156863,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.99609375,
+        l: 1.0,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.03125,
+        l: 0.03137255,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.0703125,
+        l: 0.07058824,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.109375,
+        l: 0.10980392,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.1484375,
+        l: 0.14901961,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.1875,
+        l: 0.1882353,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.2265625,
+        l: 0.22745098,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.265625,
+        l: 0.26666668,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.3046875,
+        l: 0.30588236,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.34375,
+        l: 0.34509805,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.375,
+        l: 0.3764706,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.3984375,
+        l: 0.4,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.4609375,
+        l: 0.4627451,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.5,
+        l: 0.5019608,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.5390625,
+        l: 0.5411765,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.578125,
+        l: 0.5803922,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.6171875,
+        l: 0.61960787,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.65625,
+        l: 0.65882355,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.6953125,
+        l: 0.69803923,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.734375,
+        l: 0.7372549,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.7734375,
+        l: 0.7764706,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.8125,
+        l: 0.8156863,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.8515625,
+        l: 0.85490197,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.890625,
+        l: 0.89411765,
     },
     Hsl {
         h: 0.0,
         s: 0.0,
-        l: 0.9296875,
+        l: 0.93333334,
     },
 ];
 
@@ -0,0 +1,106 @@
+use crate::*;
+
+/// One of the 16 colors of the basic ANSI palette, supported
+/// by nearly all terminals, including the Linux console.
+///
+/// The first 8 ones are the only colors of the most limited terminals.
+///
+/// The RGB values are the ones of [ANSI_TO_RGB] and [Palette::DEFAULT],
+/// which differ from the xterm defaults of [Palette::XTERM], and
+/// terminals often change them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Ansi16 {
+    Black = 0,
+    Red = 1,
+    Green = 2,
+    Yellow = 3,
+    Blue = 4,
+    Magenta = 5,
+    Cyan = 6,
+    White = 7,
+    BrightBlack = 8,
+    BrightRed = 9,
+    BrightGreen = 10,
+    BrightYellow = 11,
+    BrightBlue = 12,
+    BrightMagenta = 13,
+    BrightCyan = 14,
+    BrightWhite = 15,
+}
+
+impl Ansi16 {
+    /// All 16 colors, ordered by code
+    pub const ALL: [Ansi16; 16] = [
+        Self::Black,
+        Self::Red,
+        Self::Green,
+        Self::Yellow,
+        Self::Blue,
+        Self::Magenta,
+        Self::Cyan,
+        Self::White,
+        Self::BrightBlack,
+        Self::BrightRed,
+        Self::BrightGreen,
+        Self::BrightYellow,
+        Self::BrightBlue,
+        Self::BrightMagenta,
+        Self::BrightCyan,
+        Self::BrightWhite,
+    ];
+    /// Return the color with the given code, if it's in `[0,15]`
+    pub const fn from_code(code: u8) -> Option<Self> {
+        if code < 16 {
+            Some(Self::ALL[code as usize])
+        } else {
+            None
+        }
+    }
+    /// Return the ANSI code, in `[0,15]`
+    pub const fn code(self) -> u8 {
+        self as u8
+    }
+    /// Tell whether this color is in the "bright" half of the palette
+    pub const fn is_bright(self) -> bool {
+        self.code() > 7
+    }
+    pub const fn to_ansi(self) -> AnsiColor {
+        AnsiColor::new(self.code())
+    }
+    pub const fn to_rgb(self) -> Rgb {
+        self.to_ansi().to_rgb()
+    }
+    pub const fn to_hsl(self) -> Hsl {
+        self.to_ansi().to_hsl()
+    }
+}
+
+impl TryFrom<u8> for Ansi16 {
+    type Error = CoolorError;
+    fn try_from(code: u8) -> Result<Self, Self::Error> {
+        Self::from_code(code).ok_or(CoolorError::OutOfRange {
+            component: "code",
+            value: code as f32,
+        })
+    }
+}
+
+impl From<Ansi16> for AnsiColor {
+    fn from(ansi16: Ansi16) -> Self {
+        ansi16.to_ansi()
+    }
+}
+
+/// check going from ansi16 to rgb and back makes us fall on the first color
+#[test]
+fn test_ansi16_to_rgb_to_ansi16() {
+    for c in Ansi16::ALL {
+        assert_eq!(c, c.to_rgb().to_ansi16());
+        if !c.is_bright() {
+            assert_eq!(c, c.to_rgb().to_ansi8());
+        }
+    }
+    assert_eq!(Ansi16::Red, Rgb::new(255, 0, 0).to_ansi8());
+    assert_eq!(Ansi16::BrightRed, Rgb::new(240, 10, 20).to_ansi16());
+}
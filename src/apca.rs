@@ -0,0 +1,79 @@
+use crate::*;
+
+// Constants of APCA-W3 0.0.98G-4g
+const MAIN_TRC: f32 = 2.4;
+const NORM_BG: f32 = 0.56;
+const NORM_TXT: f32 = 0.57;
+const REV_TXT: f32 = 0.62;
+const REV_BG: f32 = 0.65;
+const BLK_THRS: f32 = 0.022;
+const BLK_CLMP: f32 = 1.414;
+const SCALE_BOW: f32 = 1.14;
+const SCALE_WOB: f32 = 1.14;
+const LO_BOW_OFFSET: f32 = 0.027;
+const LO_WOB_OFFSET: f32 = 0.027;
+const DELTA_Y_MIN: f32 = 0.0005;
+const LO_CLIP: f32 = 0.1;
+
+impl Color {
+    /// Compute the APCA lightness contrast (Lc) of a text over a background
+    ///
+    /// APCA is the contrast method proposed for WCAG 3. Contrary to the
+    /// WCAG 2 contrast ratio, it isn't symmetric: the result is positive
+    /// for dark text on a light background and negative for light text on
+    /// a dark background. Its absolute value goes from 0 (no contrast) to
+    /// about 106 (black on white) or 108 (white on black).
+    /// A Lc of 60 is about the minimum for body text, 75 being preferred.
+    ///
+    /// Reference: <https://github.com/Myndex/apca-w3> (version 0.0.98G-4g)
+    pub fn apca_contrast<T: Into<Color>, B: Into<Color>>(text: T, background: B) -> f32 {
+        let y_txt = apca_luminance(text.into().rgb());
+        let y_bg = apca_luminance(background.into().rgb());
+        if (y_bg - y_txt).abs() < DELTA_Y_MIN {
+            return 0.0;
+        }
+        let lc = if y_bg > y_txt {
+            // dark text on light background
+            let sapc = (libm::powf(y_bg, NORM_BG) - libm::powf(y_txt, NORM_TXT)) * SCALE_BOW;
+            if sapc < LO_CLIP {
+                0.0
+            } else {
+                sapc - LO_BOW_OFFSET
+            }
+        } else {
+            // light text on dark background
+            let sapc = (libm::powf(y_bg, REV_BG) - libm::powf(y_txt, REV_TXT)) * SCALE_WOB;
+            if sapc > -LO_CLIP {
+                0.0
+            } else {
+                sapc + LO_WOB_OFFSET
+            }
+        };
+        lc * 100.0
+    }
+}
+
+/// The screen luminance estimated by APCA, with a soft
+/// clamp of the near black colors
+fn apca_luminance(rgb: Rgb) -> f32 {
+    let c = |v: u8| libm::powf(v as f32 / 255.0, MAIN_TRC);
+    let y = 0.212_672_9 * c(rgb.r) + 0.715_152_2 * c(rgb.g) + 0.072_175 * c(rgb.b);
+    if y < BLK_THRS {
+        y + libm::powf(BLK_THRS - y, BLK_CLMP)
+    } else {
+        y
+    }
+}
+
+#[test]
+fn test_apca_contrast() {
+    let black = Rgb::new(0, 0, 0);
+    let white = Rgb::new(255, 255, 255);
+    let grey = Rgb::new(136, 136, 136);
+    let lc = |text: Rgb, background: Rgb| Color::apca_contrast(text, background);
+    assert!((lc(black, white) - 106.04).abs() < 0.01);
+    assert!((lc(white, black) + 107.88).abs() < 0.01);
+    assert!((lc(grey, white) - 63.06).abs() < 0.01);
+    assert!((lc(white, grey) + 68.54).abs() < 0.01);
+    assert_eq!(lc(grey, grey), 0.0);
+}
@@ -0,0 +1,116 @@
+use crate::*;
+
+/// Thresholds of an [audit]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AuditOptions {
+    /// minimal [DeltaE::Ciede2000] difference between any two colors
+    pub min_delta_e: f32,
+    /// the background the colors are displayed on, if contrast
+    /// must be checked
+    pub background: Option<Color>,
+    /// minimal WCAG contrast ratio between the colors and the background
+    pub min_contrast_ratio: f32,
+}
+
+impl Default for AuditOptions {
+    /// A minimal ΔE of 5 and, when there's a background,
+    /// the AA contrast ratio for normal text
+    fn default() -> Self {
+        Self {
+            min_delta_e: 5.0,
+            background: None,
+            min_contrast_ratio: WcagLevel::Aa.min_contrast_ratio(TextSize::Normal),
+        }
+    }
+}
+
+/// A distinguishability problem found by [audit], colors
+/// being designated by their index in the audited slice
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AuditIssue {
+    /// two colors would be displayed the same on a 256 colors terminal
+    SameAnsi {
+        a: usize,
+        b: usize,
+        ansi: AnsiColor,
+    },
+    /// two colors are too similar
+    TooSimilar { a: usize, b: usize, delta_e: f32 },
+    /// a color doesn't contrast enough with the background
+    LowContrast { index: usize, contrast_ratio: f32 },
+}
+
+/// Check that the colors, for example the ones of a syntax highlighting
+/// theme, can be told apart, and iterate over the problems found
+///
+/// Pairs are reported first, in order, then the colors with an
+/// insufficient contrast with the background.
+///
+/// ```
+/// use coolor::*;
+/// let colors = [
+///     Color::Rgb(Rgb::new(240, 80, 80)),
+///     Color::Rgb(Rgb::new(245, 85, 80)),
+///     Color::Rgb(Rgb::new(30, 30, 30)),
+/// ];
+/// let options = AuditOptions {
+///     background: Some(Rgb::new(0, 0, 0).into()),
+///     ..Default::default()
+/// };
+/// let mut issues = audit(&colors, options);
+/// assert!(matches!(issues.next(), Some(AuditIssue::SameAnsi { a: 0, b: 1, .. })));
+/// assert!(matches!(issues.next(), Some(AuditIssue::TooSimilar { a: 0, b: 1, .. })));
+/// assert!(matches!(issues.next(), Some(AuditIssue::LowContrast { index: 2, .. })));
+/// assert!(issues.next().is_none());
+/// ```
+pub fn audit(colors: &[Color], options: AuditOptions) -> impl Iterator<Item = AuditIssue> + '_ {
+    let n = colors.len();
+    let pair_issues = (0..n)
+        .flat_map(move |a| (a + 1..n).map(move |b| (a, b)))
+        .flat_map(move |(a, b)| {
+            let (ca, cb) = (colors[a], colors[b]);
+            let ansi = ca.rgb().to_ansi();
+            let same_ansi =
+                (ansi == cb.rgb().to_ansi()).then_some(AuditIssue::SameAnsi { a, b, ansi });
+            let delta_e = ca.delta_e(cb, DeltaE::Ciede2000);
+            let too_similar =
+                (delta_e < options.min_delta_e).then_some(AuditIssue::TooSimilar { a, b, delta_e });
+            same_ansi.into_iter().chain(too_similar)
+        });
+    let contrast_issues = options.background.into_iter().flat_map(move |background| {
+        colors.iter().enumerate().filter_map(move |(index, color)| {
+            let contrast_ratio = color.contrast_ratio(background);
+            (contrast_ratio < options.min_contrast_ratio)
+                .then_some(AuditIssue::LowContrast { index, contrast_ratio })
+        })
+    });
+    pair_issues.chain(contrast_issues)
+}
+
+#[test]
+fn test_audit() {
+    let colors = [
+        Color::Ansi(AnsiColor::new(196)),
+        Color::Rgb(Rgb::new(255, 255, 255)),
+        Color::Rgb(Rgb::new(250, 5, 5)),
+        Color::Rgb(Rgb::new(0, 0, 160)),
+    ];
+    let options = AuditOptions {
+        min_delta_e: 3.0,
+        background: Some(Color::Rgb(Rgb::new(255, 255, 255))),
+        min_contrast_ratio: 3.0,
+    };
+    let mut same_ansi = 0;
+    let mut too_similar = 0;
+    let mut low_contrast = 0;
+    for issue in audit(&colors, options) {
+        match issue {
+            AuditIssue::SameAnsi { a: 0, b: 2, .. } => same_ansi += 1,
+            AuditIssue::TooSimilar { a: 0, b: 2, .. } => too_similar += 1,
+            AuditIssue::LowContrast { index: 1, .. } => low_contrast += 1,
+            _ => panic!("unexpected issue {issue:?}"),
+        }
+    }
+    assert_eq!((same_ansi, too_similar, low_contrast), (1, 1, 1));
+    assert_eq!(audit(&colors, AuditOptions::default()).count(), 2);
+}
@@ -3,12 +3,16 @@ use crate::*;
 #[cfg(feature = "crossterm")]
 use crossterm::style::Color as CC;
 
-/// Color type, may be Ansi, Hsl or Rgb
-#[derive(Clone, Copy, Debug)]
+/// Color type, may be Ansi, Hsl, Rgb, Lab, Lch, Oklab or Oklch
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Color {
     Ansi(AnsiColor),
     Hsl(Hsl),
     Rgb(Rgb),
+    Lab(Lab),
+    Lch(Lch),
+    Oklab(Oklab),
+    Oklch(Oklch),
 }
 
 impl Color {
@@ -17,6 +21,32 @@ impl Color {
             Self::Ansi(ansi) => ansi,
             Self::Hsl(hsl) => hsl.to_ansi(),
             Self::Rgb(rgb) => rgb.to_ansi(),
+            Self::Lab(lab) => lab.to_ansi(),
+            Self::Lch(lch) => lch.to_ansi(),
+            Self::Oklab(oklab) => oklab.to_ansi(),
+            Self::Oklch(oklch) => oklch.to_ansi(),
+        }
+    }
+    /// Return the nearest color of the 16 colors ANSI palette
+    pub fn ansi16(self) -> Ansi16 {
+        match self {
+            Self::Ansi(AnsiColor { code }) if code < 16 => Ansi16::ALL[code as usize],
+            _ => self.rgb().to_ansi16(),
+        }
+    }
+    /// Return the nearest color of the 8 basic ANSI colors
+    pub fn ansi8(self) -> Ansi16 {
+        match self {
+            Self::Ansi(AnsiColor { code }) if code < 8 => Ansi16::ALL[code as usize],
+            _ => self.rgb().to_ansi8(),
+        }
+    }
+    /// Return the nearest ANSI color according to the given metric
+    /// (an ANSI color is returned as is)
+    pub fn ansi_with<M: ColorDistance>(self) -> AnsiColor {
+        match self {
+            Self::Ansi(ansi) => ansi,
+            _ => self.rgb().to_ansi_with::<M>(),
         }
     }
     pub fn hsl(self) -> Hsl {
@@ -24,6 +54,10 @@ impl Color {
             Self::Ansi(ansi) => ansi.to_hsl(),
             Self::Hsl(hsl) => hsl,
             Self::Rgb(rgb) => rgb.to_hsl(),
+            Self::Lab(lab) => lab.to_hsl(),
+            Self::Lch(lch) => lch.to_hsl(),
+            Self::Oklab(oklab) => oklab.to_hsl(),
+            Self::Oklch(oklch) => oklch.to_hsl(),
         }
     }
     pub fn rgb(self) -> Rgb {
@@ -31,11 +65,47 @@ impl Color {
             Self::Ansi(ansi) => ansi.to_rgb(),
             Self::Hsl(hsl) => hsl.to_rgb(),
             Self::Rgb(rgb) => rgb,
+            Self::Lab(lab) => lab.to_rgb(),
+            Self::Lch(lch) => lch.to_rgb(),
+            Self::Oklab(oklab) => oklab.to_rgb(),
+            Self::Oklch(oklch) => oklch.to_rgb(),
+        }
+    }
+    pub fn lab(self) -> Lab {
+        match self {
+            Self::Lab(lab) => lab,
+            Self::Lch(lch) => lch.to_lab(),
+            _ => self.rgb().to_lab(),
+        }
+    }
+    pub fn lch(self) -> Lch {
+        match self {
+            Self::Lab(lab) => lab.to_lch(),
+            Self::Lch(lch) => lch,
+            _ => self.rgb().to_lch(),
+        }
+    }
+    pub fn oklab(self) -> Oklab {
+        match self {
+            Self::Oklab(oklab) => oklab,
+            Self::Oklch(oklch) => oklch.to_oklab(),
+            _ => self.rgb().to_oklab(),
+        }
+    }
+    pub fn oklch(self) -> Oklch {
+        match self {
+            Self::Oklab(oklab) => oklab.to_oklch(),
+            Self::Oklch(oklch) => oklch,
+            _ => self.rgb().to_oklch(),
         }
     }
     pub fn luma(self) -> f32 {
         self.rgb().luma()
     }
+    /// Compute the difference with another color, using a standard formula
+    pub fn delta_e<O: Into<Color>>(self, other: O, metric: DeltaE) -> f32 {
+        metric.compute(self, other)
+    }
     /// compute a natural feeling intermediate between two colors
     pub fn blend<C1: Into<Color>, C2: Into<Color>>(c1: C1, w1: f32, c2: C2, w2: f32) -> Self {
         let c1: Color = c1.into();
@@ -56,6 +126,13 @@ impl Color {
         }
         .into()
     }
+    /// compute an intermediate between two colors in the OKLab space,
+    /// which gives perceptually regular gradients without muddy midpoints
+    pub fn blend_oklab<C1: Into<Color>, C2: Into<Color>>(c1: C1, w1: f32, c2: C2, w2: f32) -> Self {
+        let c1: Color = c1.into();
+        let c2: Color = c2.into();
+        Oklab::mix(c1.oklab(), w1, c2.oklab(), w2).into()
+    }
 }
 
 impl From<AnsiColor> for Color {
@@ -63,6 +140,11 @@ impl From<AnsiColor> for Color {
         Self::Ansi(ansi)
     }
 }
+impl From<Ansi16> for Color {
+    fn from(ansi16: Ansi16) -> Self {
+        Self::Ansi(ansi16.to_ansi())
+    }
+}
 impl From<Rgb> for Color {
     fn from(rgb: Rgb) -> Self {
         Self::Rgb(rgb)
@@ -73,6 +155,26 @@ impl From<Hsl> for Color {
         Self::Hsl(rgb)
     }
 }
+impl From<Lab> for Color {
+    fn from(lab: Lab) -> Self {
+        Self::Lab(lab)
+    }
+}
+impl From<Lch> for Color {
+    fn from(lch: Lch) -> Self {
+        Self::Lch(lch)
+    }
+}
+impl From<Oklab> for Color {
+    fn from(oklab: Oklab) -> Self {
+        Self::Oklab(oklab)
+    }
+}
+impl From<Oklch> for Color {
+    fn from(oklch: Oklch) -> Self {
+        Self::Oklch(oklch)
+    }
+}
 impl From<u8> for Color {
     fn from(code: u8) -> Self {
         Self::Ansi(AnsiColor::new(code))
@@ -112,8 +214,8 @@ impl Into<CC> for Color {
         match self {
             Self::Ansi(AnsiColor { code }) => CC::AnsiValue(code),
             Self::Rgb(Rgb { r, g, b }) => CC::Rgb { r, g, b },
-            Self::Hsl(hsl) => {
-                let Rgb { r, g, b } = hsl.to_rgb();
+            _ => {
+                let Rgb { r, g, b } = self.rgb();
                 CC::Rgb { r, g, b }
             }
         }
@@ -144,6 +246,15 @@ fn test_ansi_to_hsl_to_ansi() {
         assert_eq!(c1, c3);
     }
 }
+/// check going from ansi to hsl then to rgb gives the color of the ansi to rgb table
+#[test]
+fn test_ansi_to_hsl_to_rgb() {
+    for code in 0..=255 {
+        let ansi = AnsiColor { code };
+        assert_eq!(ansi.to_hsl(), ansi.to_rgb().to_hsl());
+        assert_eq!(ansi.to_hsl().to_rgb(), ansi.to_rgb());
+    }
+}
 #[test]
 fn test_rgb_to_hsl() {
     assert!(Rgb::new(255, 0, 0).to_hsl().near(Hsl::new(0.0, 1.0, 0.5))); // red
@@ -0,0 +1,312 @@
+use crate::*;
+
+/// Size of a text, as defined by WCAG for contrast requirements
+///
+/// Large text is at least 18 points, or 14 points and bold.
+/// In a terminal, all text is usually normal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextSize {
+    Normal,
+    Large,
+}
+
+/// A conformance level of the WCAG 2 contrast requirements
+///
+/// See <https://www.w3.org/TR/WCAG21/#contrast-minimum>
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WcagLevel {
+    /// the minimum level (success criterion 1.4.3)
+    Aa,
+    /// the enhanced level (success criterion 1.4.6)
+    Aaa,
+}
+
+impl WcagLevel {
+    /// Return the minimal contrast ratio required for this level
+    pub const fn min_contrast_ratio(self, size: TextSize) -> f32 {
+        match (self, size) {
+            (Self::Aa, TextSize::Normal) => 4.5,
+            (Self::Aa, TextSize::Large) => 3.0,
+            (Self::Aaa, TextSize::Normal) => 7.0,
+            (Self::Aaa, TextSize::Large) => 4.5,
+        }
+    }
+    /// Return the highest level reached with the given contrast ratio, if any
+    pub fn for_contrast_ratio(ratio: f32, size: TextSize) -> Option<Self> {
+        if ratio >= Self::Aaa.min_contrast_ratio(size) {
+            Some(Self::Aaa)
+        } else if ratio >= Self::Aa.min_contrast_ratio(size) {
+            Some(Self::Aa)
+        } else {
+            None
+        }
+    }
+}
+
+/// A measure of the contrast between a text and its background
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ContrastMetric {
+    /// Difference of [Color::luma], fast but rough
+    Luma,
+    /// WCAG 2 contrast ratio, see [Color::contrast_ratio]
+    Wcag,
+    /// APCA lightness contrast, see [Color::apca_contrast], more
+    /// reliable than WCAG 2 for light text on dark backgrounds
+    Apca,
+}
+
+impl ContrastMetric {
+    /// Return the contrast of the text over the background, in
+    /// the unit of the metric, higher values meaning more contrast
+    pub fn contrast<T: Into<Color>, B: Into<Color>>(self, text: T, background: B) -> f32 {
+        let text: Color = text.into();
+        let background: Color = background.into();
+        match self {
+            Self::Luma => (text.luma() - background.luma()).abs(),
+            Self::Wcag => text.contrast_ratio(background),
+            Self::Apca => Color::apca_contrast(text, background).abs(),
+        }
+    }
+}
+
+impl LinearRgb {
+    /// Relative luminance, as defined by WCAG, from 0 (black) to 1 (white)
+    pub fn relative_luminance(self) -> f32 {
+        0.2126 * self.r + 0.7152 * self.g + 0.0722 * self.b
+    }
+}
+
+impl Rgb {
+    /// Relative luminance, as defined by WCAG, from 0 (black) to 1 (white)
+    ///
+    /// Contrary to [Rgb::luma], it's computed in linear light.
+    ///
+    /// Reference: <https://www.w3.org/TR/WCAG21/#dfn-relative-luminance>
+    pub fn relative_luminance(self) -> f32 {
+        self.to_linear().relative_luminance()
+    }
+}
+
+impl Color {
+    /// Relative luminance, as defined by WCAG, from 0 (black) to 1 (white)
+    pub fn relative_luminance(self) -> f32 {
+        self.rgb().relative_luminance()
+    }
+    /// WCAG 2 contrast ratio between two colors, from 1 (same
+    /// luminance) to 21 (black and white)
+    ///
+    /// The ratio is symmetric: it doesn't matter which
+    /// color is the text and which is the background.
+    ///
+    /// Reference: <https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio>
+    pub fn contrast_ratio<O: Into<Color>>(self, other: O) -> f32 {
+        luminance_contrast_ratio(self.relative_luminance(), other.into().relative_luminance())
+    }
+    /// Tell whether the contrast with the other color
+    /// meets the given WCAG level
+    pub fn meets_wcag<O: Into<Color>>(self, other: O, level: WcagLevel, size: TextSize) -> bool {
+        self.contrast_ratio(other) >= level.min_contrast_ratio(size)
+    }
+    /// Tell whether the contrast with the other color is enough
+    /// for the WCAG AA level
+    pub fn passes_aa<O: Into<Color>>(self, other: O, size: TextSize) -> bool {
+        self.meets_wcag(other, WcagLevel::Aa, size)
+    }
+    /// Tell whether the contrast with the other color is enough
+    /// for the WCAG AAA level
+    pub fn passes_aaa<O: Into<Color>>(self, other: O, size: TextSize) -> bool {
+        self.meets_wcag(other, WcagLevel::Aaa, size)
+    }
+    /// Return the candidate which, as a text color over this background,
+    /// gives the best WCAG 2 contrast ratio, or `None` if there's no candidate
+    ///
+    /// Use [Color::best_text_color_with] to choose the metric.
+    pub fn best_text_color<C: Into<Color> + Copy>(self, candidates: &[C]) -> Option<C> {
+        self.best_text_color_with(candidates, ContrastMetric::Wcag)
+    }
+    /// Return the candidate which, as a text color over this background,
+    /// gives the best contrast according to the metric, or `None` if
+    /// there's no candidate
+    pub fn best_text_color_with<C: Into<Color> + Copy>(
+        self,
+        candidates: &[C],
+        metric: ContrastMetric,
+    ) -> Option<C> {
+        let mut best: Option<(f32, C)> = None;
+        for &candidate in candidates {
+            let contrast = metric.contrast(candidate, self);
+            if best.is_none_or(|(c, _)| contrast > c) {
+                best = Some((contrast, candidate));
+            }
+        }
+        best.map(|(_, candidate)| candidate)
+    }
+    /// Return the highest WCAG level met by the contrast
+    /// with the other color, if any
+    pub fn wcag_level<O: Into<Color>>(self, other: O, size: TextSize) -> Option<WcagLevel> {
+        WcagLevel::for_contrast_ratio(self.contrast_ratio(other), size)
+    }
+}
+
+/// Return a color as near as possible to `fg` whose contrast
+/// ratio with `bg` is at least `min_ratio`
+///
+/// Only the lightness, in [Oklch], is changed, so that the hue is kept,
+/// the chroma being reduced only when needed to stay in the sRGB gamut.
+/// When `fg` is already contrasted enough, it's returned unchanged.
+/// When the ratio can't be reached, which can't happen under 4.58,
+/// black or white is returned, whichever contrasts most with `bg`.
+pub fn ensure_contrast<F: Into<Color>, B: Into<Color>>(fg: F, bg: B, min_ratio: f32) -> Color {
+    let fg: Color = fg.into();
+    let bg_luminance = bg.into().relative_luminance();
+    let passes = |rgb: Rgb| {
+        luminance_contrast_ratio(rgb.relative_luminance(), bg_luminance) >= min_ratio
+    };
+    if passes(fg.rgb()) {
+        return fg;
+    }
+    let oklch = fg.oklch();
+    let with_l = |l: f32| Oklch { l, ..oklch }.with_chroma_in_gamut().to_rgb();
+    // search, toward black or white, the nearest lightness giving enough contrast
+    let search = |extreme: f32| {
+        if !passes(with_l(extreme)) {
+            return None;
+        }
+        let (mut min, mut max) = (oklch.l, extreme);
+        for _ in 0..20 {
+            let l = (min + max) / 2.0;
+            if passes(with_l(l)) {
+                max = l;
+            } else {
+                min = l;
+            }
+        }
+        Some(((max - oklch.l).abs(), with_l(max)))
+    };
+    let rgb = match (search(0.0), search(1.0)) {
+        (Some((d0, rgb0)), Some((d1, rgb1))) => {
+            if d0 < d1 {
+                rgb0
+            } else {
+                rgb1
+            }
+        }
+        (Some((_, rgb)), None) | (None, Some((_, rgb))) => rgb,
+        (None, None) => {
+            // black and white contrast as much with a luminance of 0.179
+            if bg_luminance > 0.179 {
+                Rgb::new(0, 0, 0)
+            } else {
+                Rgb::new(255, 255, 255)
+            }
+        }
+    };
+    Color::Rgb(rgb)
+}
+
+/// Return the ANSI color nearest to the result of [ensure_contrast],
+/// among the colors of the 16..=255 range (the 16 first ones
+/// being usually redefined by terminal themes) whose contrast
+/// ratio with `bg` is at least `min_ratio`
+///
+/// When none reaches the ratio, the most contrasted one is returned.
+pub fn ensure_contrast_ansi<F: Into<Color>, B: Into<Color>>(
+    fg: F,
+    bg: B,
+    min_ratio: f32,
+) -> AnsiColor {
+    let fg: Color = fg.into();
+    let bg: Color = bg.into();
+    let bg_luminance = bg.relative_luminance();
+    let ratio = |ansi: AnsiColor| {
+        luminance_contrast_ratio(ansi.to_rgb().relative_luminance(), bg_luminance)
+    };
+    let ansi = fg.ansi();
+    if ansi.code >= 16 && ratio(ansi) >= min_ratio {
+        return ansi;
+    }
+    let target = ensure_contrast(fg, bg, min_ratio).oklab();
+    let mut best: Option<(f32, AnsiColor)> = None;
+    let mut most_contrasted = (0.0, AnsiColor::new(16));
+    for code in 16..=255 {
+        let ansi = AnsiColor::new(code);
+        let ansi_ratio = ratio(ansi);
+        if ansi_ratio >= min_ratio {
+            let distance = target.distance_to(ansi.to_rgb().to_oklab());
+            if best.is_none_or(|(d, _)| distance < d) {
+                best = Some((distance, ansi));
+            }
+        } else if ansi_ratio > most_contrasted.0 {
+            most_contrasted = (ansi_ratio, ansi);
+        }
+    }
+    best.map_or(most_contrasted.1, |(_, ansi)| ansi)
+}
+
+/// WCAG 2 contrast ratio between two relative luminances
+fn luminance_contrast_ratio(l1: f32, l2: f32) -> f32 {
+    let (lighter, darker) = if l1 > l2 { (l1, l2) } else { (l2, l1) };
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+#[test]
+fn test_contrast_ratio() {
+    let black = Color::Rgb(Rgb::new(0, 0, 0));
+    let white = Color::Rgb(Rgb::new(255, 255, 255));
+    assert!((black.contrast_ratio(white) - 21.0).abs() < 0.001);
+    assert!((white.contrast_ratio(black) - 21.0).abs() < 0.001);
+    assert!((white.contrast_ratio(white) - 1.0).abs() < 0.001);
+    let grey = Color::Rgb(Rgb::new(0x77, 0x77, 0x77));
+    assert!((grey.contrast_ratio(white) - 4.48).abs() < 0.01);
+    assert!(!grey.passes_aa(white, TextSize::Normal));
+    assert!(grey.passes_aa(white, TextSize::Large));
+    assert_eq!(black.wcag_level(white, TextSize::Normal), Some(WcagLevel::Aaa));
+    assert_eq!(grey.wcag_level(white, TextSize::Normal), None);
+}
+#[test]
+fn test_best_text_color() {
+    let black = Rgb::new(0, 0, 0);
+    let white = Rgb::new(255, 255, 255);
+    let bw = [black, white];
+    let metrics = [ContrastMetric::Luma, ContrastMetric::Wcag, ContrastMetric::Apca];
+    for metric in metrics {
+        let navy = Color::Rgb(Rgb::new(0, 0, 128));
+        assert_eq!(navy.best_text_color_with(&bw, metric), Some(white));
+        let yellow = Color::Rgb(Rgb::new(255, 255, 0));
+        assert_eq!(yellow.best_text_color_with(&bw, metric), Some(black));
+    }
+    // WCAG 2 prefers black on mid-tones, APCA prefers white
+    let blue = Color::Rgb(Rgb::new(80, 120, 200));
+    assert_eq!(blue.best_text_color(&bw), Some(black));
+    assert_eq!(blue.best_text_color_with(&bw, ContrastMetric::Apca), Some(white));
+    let ansi_candidates = [AnsiColor::new(16), AnsiColor::new(231), AnsiColor::new(226)];
+    let bg = Color::Ansi(AnsiColor::new(19));
+    assert_eq!(bg.best_text_color(&ansi_candidates), Some(AnsiColor::new(231)));
+    assert_eq!(bg.best_text_color::<Rgb>(&[]), None);
+}
+#[test]
+fn test_ensure_contrast() {
+    let backgrounds = [
+        Rgb::new(0, 0, 0),
+        Rgb::new(30, 30, 40),
+        Rgb::new(120, 100, 20),
+        Rgb::new(250, 250, 240),
+    ];
+    for bg in backgrounds {
+        for code in (0..=255).step_by(5) {
+            let fg = AnsiColor::new(code);
+            let rgb = ensure_contrast(fg, bg, 4.5);
+            assert!(rgb.contrast_ratio(bg) >= 4.5);
+            let ansi = ensure_contrast_ansi(fg, bg, 4.5);
+            assert!(ansi.code >= 16);
+            assert!(Color::from(ansi).contrast_ratio(bg) >= 4.5);
+        }
+    }
+    // an already contrasted color isn't changed
+    let fg = Rgb::new(230, 100, 100);
+    assert_eq!(ensure_contrast(fg, Rgb::new(0, 0, 0), 4.5), Color::Rgb(fg));
+    // the hue is kept
+    let fg = Rgb::new(255, 80, 80);
+    let adjusted = ensure_contrast(fg, Rgb::new(255, 255, 255), 7.0).oklch();
+    assert!((adjusted.h - fg.to_oklch().h).abs() < 2.0);
+}
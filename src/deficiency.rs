@@ -0,0 +1,229 @@
+use crate::*;
+
+/// A color vision deficiency, for simulating how a color is seen
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Deficiency {
+    /// lack of the long-wavelength (red) cones
+    Protanopia,
+    /// lack of the medium-wavelength (green) cones
+    Deuteranopia,
+    /// lack of the short-wavelength (blue) cones
+    Tritanopia,
+    /// total color blindness, only the luminance is perceived
+    Achromatopsia,
+}
+
+impl Deficiency {
+    /// Return the matrix transforming linear RGB for the full deficiency
+    ///
+    /// Dichromacies use the matrices of Machado, Oliveira and Fernandes,
+    /// "A Physiologically-based Model for Simulation of Color Vision
+    /// Deficiency" (2009), for a severity of 1.
+    pub const fn matrix(self) -> [[f32; 3]; 3] {
+        match self {
+            Self::Protanopia => [
+                [0.152286, 1.052583, -0.204868],
+                [0.114503, 0.786281, 0.099216],
+                [-0.003882, -0.048116, 1.051998],
+            ],
+            Self::Deuteranopia => [
+                [0.367322, 0.860646, -0.227968],
+                [0.280085, 0.672501, 0.047413],
+                [-0.011820, 0.042940, 0.968881],
+            ],
+            Self::Tritanopia => [
+                [1.255528, -0.076749, -0.178779],
+                [-0.078411, 0.930809, 0.147602],
+                [0.004733, 0.691367, 0.303900],
+            ],
+            Self::Achromatopsia => [
+                [0.2126, 0.7152, 0.0722],
+                [0.2126, 0.7152, 0.0722],
+                [0.2126, 0.7152, 0.0722],
+            ],
+        }
+    }
+    /// Return how a color is seen with this deficiency, `severity` in
+    /// `[0,1]` going from normal vision to the full deficiency
+    ///
+    /// Partial severities interpolate between the identity and the
+    /// matrix of the full deficiency.
+    pub fn simulate_linear(self, linear: LinearRgb, severity: f32) -> LinearRgb {
+        let s = severity.clamp(0.0, 1.0);
+        let m = self.matrix();
+        let LinearRgb { r, g, b } = linear;
+        let row = |i: usize, v: f32| {
+            let simulated = m[i][0] * r + m[i][1] * g + m[i][2] * b;
+            (1.0 - s) * v + s * simulated
+        };
+        LinearRgb::new(row(0, r), row(1, g), row(2, b))
+    }
+    /// Return the matrix distributing the error of the simulation
+    /// (what isn't perceived) onto the channels which are perceived
+    const fn error_shift(self) -> [[f32; 3]; 3] {
+        match self {
+            Self::Protanopia | Self::Deuteranopia => {
+                [[0.0, 0.0, 0.0], [0.7, 1.0, 0.0], [0.7, 0.0, 1.0]]
+            }
+            Self::Tritanopia => [[1.0, 0.0, 0.7], [0.0, 1.0, 0.7], [0.0, 0.0, 0.0]],
+            Self::Achromatopsia => [[0.0; 3]; 3],
+        }
+    }
+}
+
+impl Color {
+    /// Return how this color is seen with the given deficiency,
+    /// `severity` being in `[0,1]`
+    pub fn simulate(self, deficiency: Deficiency, severity: f32) -> Rgb {
+        deficiency
+            .simulate_linear(self.rgb().to_linear(), severity)
+            .to_rgb()
+    }
+    /// Return the nearest ANSI color of how this color
+    /// is seen with the given deficiency
+    pub fn simulate_ansi(self, deficiency: Deficiency, severity: f32) -> AnsiColor {
+        self.simulate(deficiency, severity).to_ansi()
+    }
+    /// Shift this color so that it's better distinguished with the
+    /// given deficiency, by moving what isn't perceived to the
+    /// channels which are perceived (Fidaner, Lin, Ozguven)
+    ///
+    /// ANSI colors are kept ANSI, in the same range (`0..16` or
+    /// `16..=255`). Achromatopsia can't be corrected
+    /// this way and leaves the color unchanged.
+    pub fn daltonize(self, deficiency: Deficiency) -> Color {
+        if deficiency == Deficiency::Achromatopsia {
+            return self;
+        }
+        let rgb = self.rgb();
+        let simulated = self.simulate(deficiency, 1.0);
+        let (r, g, b) = rgb.parts();
+        let (sr, sg, sb) = simulated.parts();
+        let error = [r - sr, g - sg, b - sb];
+        let m = deficiency.error_shift();
+        let shifted = |v: f32, i: usize| {
+            let shift = m[i][0] * error[0] + m[i][1] * error[1] + m[i][2] * error[2];
+            (v + shift).clamp(0.0, 1.0)
+        };
+        let daltonized: Rgb = (shifted(r, 0), shifted(g, 1), shifted(b, 2)).into();
+        match self {
+            Self::Ansi(ansi) if ansi.code < 16 => Self::Ansi(daltonized.to_ansi16().to_ansi()),
+            Self::Ansi(_) => Self::Ansi(daltonized.to_ansi()),
+            _ => Self::Rgb(daltonized),
+        }
+    }
+}
+
+/// Return the CIEDE2000 difference between two colors,
+/// as seen with the full deficiency
+fn simulated_delta_e(deficiency: Deficiency, c1: Color, c2: Color) -> f32 {
+    DeltaE::Ciede2000.compute(c1.simulate(deficiency, 1.0), c2.simulate(deficiency, 1.0))
+}
+
+/// Shift colors, as little as possible, so that any two of them
+/// differ by at least `min_delta_e` (CIEDE2000) when seen with the
+/// full deficiency, and return whether this was achieved.
+///
+/// The first color is never changed, and the other ones only
+/// when too near to a previous one, so the most important colors
+/// should come first. ANSI colors are replaced with ANSI colors
+/// of the same range (`0..16` or `16..=255`).
+pub fn daltonize_colors(colors: &mut [Color], deficiency: Deficiency, min_delta_e: f32) -> bool {
+    let mut success = true;
+    for i in 1..colors.len() {
+        let (previous, rest) = colors.split_at_mut(i);
+        let original = rest[0];
+        let is_distinct = |c: Color| {
+            previous
+                .iter()
+                .all(|&p| simulated_delta_e(deficiency, p, c) >= min_delta_e)
+        };
+        if is_distinct(original) {
+            continue;
+        }
+        // among the distinct candidates, keep the nearest to the original
+        let mut best: Option<(f32, Color)> = None;
+        let mut consider = |candidate: Color| {
+            let cost = original.delta_e(candidate, DeltaE::Ciede2000);
+            if best.is_none_or(|(c, _)| cost < c) && is_distinct(candidate) {
+                best = Some((cost, candidate));
+            }
+        };
+        if let Color::Ansi(ansi) = original {
+            let codes = if ansi.code < 16 { 0..=15 } else { 16..=255 };
+            for code in codes {
+                consider(Color::Ansi(AnsiColor::new(code)));
+            }
+        } else {
+            consider(original.daltonize(deficiency));
+            let oklch = original.oklch();
+            for dl in -6..=6 {
+                for dh in -6..=6 {
+                    let l = (oklch.l + dl as f32 * 0.05).clamp(0.0, 1.0);
+                    let h = (oklch.h + dh as f32 * 15.0 + 360.0) % 360.0;
+                    let variant = Oklch { l, h, ..oklch }.with_chroma_in_gamut();
+                    consider(Color::Rgb(variant.to_rgb()));
+                }
+            }
+        }
+        match best {
+            Some((_, color)) => rest[0] = color,
+            None => success = false,
+        }
+    }
+    success
+}
+
+#[test]
+fn test_simulate() {
+    let red = Color::Rgb(Rgb::new(255, 0, 0));
+    let green = Color::Rgb(Rgb::new(0, 255, 0));
+    for deficiency in [
+        Deficiency::Protanopia,
+        Deficiency::Deuteranopia,
+        Deficiency::Tritanopia,
+        Deficiency::Achromatopsia,
+    ] {
+        // greys are seen the same
+        for v in [0, 60, 128, 255] {
+            let grey = Color::Rgb(Rgb::new(v, v, v));
+            let simulated = grey.simulate(deficiency, 1.0);
+            assert!(grey.delta_e(simulated, DeltaE::Ciede2000) < 1.0);
+        }
+        assert_eq!(red.simulate(deficiency, 0.0), red.rgb());
+    }
+    // red and green are hard to distinguish for protanopes and deuteranopes
+    let normal = red.delta_e(green, DeltaE::Ciede2000);
+    for deficiency in [Deficiency::Protanopia, Deficiency::Deuteranopia] {
+        let simulated = DeltaE::Ciede2000.compute(
+            red.simulate(deficiency, 1.0),
+            green.simulate(deficiency, 1.0),
+        );
+        assert!(simulated < normal / 2.0);
+    }
+    let grey = red.simulate(Deficiency::Achromatopsia, 1.0);
+    assert!(grey.r == grey.g && grey.g == grey.b);
+}
+#[test]
+fn test_daltonize_colors() {
+    let deficiency = Deficiency::Deuteranopia;
+    let mut colors = [
+        Color::Rgb(Rgb::new(200, 40, 40)),
+        Color::Rgb(Rgb::new(40, 160, 40)),
+        Color::Ansi(AnsiColor::new(166)),
+        Color::Ansi(AnsiColor::new(70)),
+    ];
+    let min_delta_e = 12.0;
+    assert!(simulated_delta_e(deficiency, colors[2], colors[3]) < min_delta_e);
+    assert!(daltonize_colors(&mut colors, deficiency, min_delta_e));
+    let daltonized = Color::Ansi(AnsiColor::new(9)).daltonize(deficiency);
+    assert!(matches!(daltonized, Color::Ansi(AnsiColor { code: 0..16 })));
+    assert_eq!(colors[0], Color::Rgb(Rgb::new(200, 40, 40)));
+    assert!(matches!(colors[2], Color::Ansi(_)));
+    assert!(matches!(colors[3], Color::Ansi(_)));
+    for (i, &a) in colors.iter().enumerate() {
+        for &b in &colors[i + 1..] {
+            assert!(simulated_delta_e(deficiency, a, b) >= min_delta_e);
+        }
+    }
+}
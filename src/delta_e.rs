@@ -0,0 +1,159 @@
+use crate::*;
+
+/// A standard formula for the difference between two colors (ΔE)
+///
+/// A ΔE of about 1 (about 0.01 for [DeltaE::Oklab]) is the
+/// smallest difference the eye may notice.
+///
+/// See <https://en.wikipedia.org/wiki/Color_difference>
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeltaE {
+    /// Euclidean distance in CIELAB (CIE 1976)
+    Cie76,
+    /// CIE 1994, with the weights for graphic arts
+    Cie94,
+    /// CIEDE2000, the most accurate of the CIE formulas
+    Ciede2000,
+    /// Euclidean distance in OKLab
+    Oklab,
+}
+
+impl DeltaE {
+    /// Compute the difference between two colors
+    ///
+    /// [DeltaE::Cie94] isn't symmetric: the first color is the reference.
+    pub fn compute<C1: Into<Color>, C2: Into<Color>>(self, c1: C1, c2: C2) -> f32 {
+        let c1: Color = c1.into();
+        let c2: Color = c2.into();
+        match self {
+            Self::Cie76 => c1.lab().delta_e_76(c2.lab()),
+            Self::Cie94 => c1.lab().delta_e_94(c2.lab()),
+            Self::Ciede2000 => c1.lab().delta_e_2000(c2.lab()),
+            Self::Oklab => c1.oklab().distance_to(c2.oklab()),
+        }
+    }
+}
+
+impl Lab {
+    /// CIE76 color difference: the euclidean distance in Lab
+    pub fn delta_e_76(self, other: Lab) -> f32 {
+        let dl = self.l - other.l;
+        let da = self.a - other.a;
+        let db = self.b - other.b;
+        libm::sqrtf(dl * dl + da * da + db * db)
+    }
+    /// CIE94 color difference, with graphic arts weights,
+    /// `self` being the reference color
+    pub fn delta_e_94(self, other: Lab) -> f32 {
+        let dl = self.l - other.l;
+        let c1 = libm::sqrtf(self.a * self.a + self.b * self.b);
+        let c2 = libm::sqrtf(other.a * other.a + other.b * other.b);
+        let dc = c1 - c2;
+        let da = self.a - other.a;
+        let db = self.b - other.b;
+        let dh2 = (da * da + db * db - dc * dc).max(0.0);
+        let sc = 1.0 + 0.045 * c1;
+        let sh = 1.0 + 0.015 * c1;
+        libm::sqrtf(dl * dl + (dc / sc) * (dc / sc) + dh2 / (sh * sh))
+    }
+    /// CIEDE2000 color difference
+    ///
+    /// Reference: Sharma, Wu, Dalal, "The CIEDE2000 color-difference formula:
+    /// implementation notes, supplementary test data, and mathematical observations"
+    pub fn delta_e_2000(self, other: Lab) -> f32 {
+        const POW25_7: f32 = 6_103_515_625.0; // 25^7
+        let c1 = libm::sqrtf(self.a * self.a + self.b * self.b);
+        let c2 = libm::sqrtf(other.a * other.a + other.b * other.b);
+        let c_bar = (c1 + c2) / 2.0;
+        let c_bar7 = libm::powf(c_bar, 7.0);
+        let g = 0.5 * (1.0 - libm::sqrtf(c_bar7 / (c_bar7 + POW25_7)));
+        let a1 = (1.0 + g) * self.a;
+        let a2 = (1.0 + g) * other.a;
+        let c1 = libm::sqrtf(a1 * a1 + self.b * self.b);
+        let c2 = libm::sqrtf(a2 * a2 + other.b * other.b);
+        let h1 = hue_degrees(self.b, a1);
+        let h2 = hue_degrees(other.b, a2);
+
+        let dl = other.l - self.l;
+        let dc = c2 - c1;
+        let dh = if c1 * c2 == 0.0 {
+            0.0
+        } else if (h2 - h1).abs() <= 180.0 {
+            h2 - h1
+        } else if h2 - h1 > 180.0 {
+            h2 - h1 - 360.0
+        } else {
+            h2 - h1 + 360.0
+        };
+        let dh = 2.0 * libm::sqrtf(c1 * c2) * libm::sinf((dh / 2.0).to_radians());
+
+        let l_bar = (self.l + other.l) / 2.0;
+        let c_bar = (c1 + c2) / 2.0;
+        let h_bar = if c1 * c2 == 0.0 {
+            h1 + h2
+        } else if (h1 - h2).abs() <= 180.0 {
+            (h1 + h2) / 2.0
+        } else if h1 + h2 < 360.0 {
+            (h1 + h2 + 360.0) / 2.0
+        } else {
+            (h1 + h2 - 360.0) / 2.0
+        };
+        let t = 1.0 - 0.17 * libm::cosf((h_bar - 30.0).to_radians())
+            + 0.24 * libm::cosf((2.0 * h_bar).to_radians())
+            + 0.32 * libm::cosf((3.0 * h_bar + 6.0).to_radians())
+            - 0.20 * libm::cosf((4.0 * h_bar - 63.0).to_radians());
+        let d_theta = 30.0 * libm::expf(-((h_bar - 275.0) / 25.0) * ((h_bar - 275.0) / 25.0));
+        let c_bar7 = libm::powf(c_bar, 7.0);
+        let rc = 2.0 * libm::sqrtf(c_bar7 / (c_bar7 + POW25_7));
+        let l50 = (l_bar - 50.0) * (l_bar - 50.0);
+        let sl = 1.0 + 0.015 * l50 / libm::sqrtf(20.0 + l50);
+        let sc = 1.0 + 0.045 * c_bar;
+        let sh = 1.0 + 0.015 * c_bar * t;
+        let rt = -libm::sinf((2.0 * d_theta).to_radians()) * rc;
+
+        let l = dl / sl;
+        let c = dc / sc;
+        let h = dh / sh;
+        libm::sqrtf(l * l + c * c + h * h + rt * c * h)
+    }
+}
+
+impl Oklab {
+    /// Euclidean distance in OKLab, a simple yet good perceptual distance
+    pub fn distance_to(self, other: Oklab) -> f32 {
+        let dl = self.l - other.l;
+        let da = self.a - other.a;
+        let db = self.b - other.b;
+        libm::sqrtf(dl * dl + da * da + db * db)
+    }
+}
+
+/// hue angle in `[0,360[`, as used in CIEDE2000
+fn hue_degrees(b: f32, a: f32) -> f32 {
+    if a == 0.0 && b == 0.0 {
+        return 0.0;
+    }
+    let h = libm::atan2f(b, a).to_degrees();
+    if h < 0.0 {
+        h + 360.0
+    } else {
+        h
+    }
+}
+
+/// check CIEDE2000 against some of the test data provided by Sharma et al.
+#[test]
+fn test_ciede2000() {
+    let pairs = [
+        (Lab::new(50.0, 2.6772, -79.7751), Lab::new(50.0, 0.0, -82.7485), 2.0425),
+        (Lab::new(50.0, 0.0, 0.0), Lab::new(50.0, -1.0, 2.0), 2.3669),
+        (Lab::new(50.0, 2.49, -0.001), Lab::new(50.0, -2.49, 0.0011), 7.2195),
+        (Lab::new(50.0, 2.5, 0.0), Lab::new(73.0, 25.0, -18.0), 27.1492),
+        (Lab::new(60.2574, -34.0099, 36.2677), Lab::new(60.4626, -34.1751, 39.4387), 1.2644),
+        (Lab::new(2.0776, 0.0795, -1.135), Lab::new(0.9033, -0.0636, -0.5514), 0.9082),
+    ];
+    for (c1, c2, expected) in pairs {
+        assert!((c1.delta_e_2000(c2) - expected).abs() < 0.001);
+        assert!((c2.delta_e_2000(c1) - expected).abs() < 0.001);
+    }
+}
@@ -0,0 +1,116 @@
+use crate::*;
+
+/// A measure of how different two RGB colors look, used to
+/// choose the nearest ANSI color of a RGB one.
+///
+/// The implementations are unit structs, so that the metric
+/// can be chosen with a type parameter, for example
+/// `rgb.to_ansi_with::<OklabDistance>()`.
+pub trait ColorDistance {
+    /// Distance between two colors, only meaningful for
+    /// comparing it to other distances of the same metric
+    fn distance(a: Rgb, b: Rgb) -> f32;
+    /// Return the nearest ANSI color in the 16..=255 range
+    ///
+    /// The default implementation tries all 240 colors.
+    fn nearest_ansi(rgb: Rgb) -> AnsiColor
+    where
+        Self: Sized,
+    {
+        rgb.nearest_ansi_in_range_with::<Self>(16, 255)
+    }
+}
+
+/// The "redmean" weighted euclidean RGB distance of [Rgb::distance_to]
+///
+/// This is the metric used by [Rgb::to_ansi], which is faster but
+/// only searches a slice of the ANSI cube so doesn't always find
+/// the nearest color.
+pub struct RedmeanDistance;
+
+impl ColorDistance for RedmeanDistance {
+    #[inline(always)]
+    fn distance(a: Rgb, b: Rgb) -> f32 {
+        a.distance_to(b)
+    }
+}
+
+/// Plain euclidean distance between the gamma-encoded sRGB components
+pub struct RgbDistance;
+
+impl ColorDistance for RgbDistance {
+    fn distance(a: Rgb, b: Rgb) -> f32 {
+        let r = a.r as f32 - b.r as f32;
+        let g = a.g as f32 - b.g as f32;
+        let b = a.b as f32 - b.b as f32;
+        r * r + g * g + b * b
+    }
+}
+
+/// The CIEDE2000 color difference, see [Lab::delta_e_2000]
+///
+/// This is the most accurate metric but also the slowest one.
+pub struct Ciede2000Distance;
+
+impl ColorDistance for Ciede2000Distance {
+    fn distance(a: Rgb, b: Rgb) -> f32 {
+        a.to_lab().delta_e_2000(b.to_lab())
+    }
+    fn nearest_ansi(rgb: Rgb) -> AnsiColor {
+        let lab = rgb.to_lab();
+        nearest_ansi_by(|ansi| lab.delta_e_2000(ansi.to_rgb().to_lab()))
+    }
+}
+
+/// Euclidean distance in the OKLab space, see [Oklab::distance_to]
+pub struct OklabDistance;
+
+impl ColorDistance for OklabDistance {
+    fn distance(a: Rgb, b: Rgb) -> f32 {
+        a.to_oklab().distance_to(b.to_oklab())
+    }
+    fn nearest_ansi(rgb: Rgb) -> AnsiColor {
+        let oklab = rgb.to_oklab();
+        nearest_ansi_by(|ansi| oklab.distance_to(ansi.to_rgb().to_oklab()))
+    }
+}
+
+/// Return the ANSI color of the 16..=255 range minimizing the distance
+fn nearest_ansi_by<F: Fn(AnsiColor) -> f32>(distance: F) -> AnsiColor {
+    let mut best = AnsiColor { code: 16 };
+    let mut smallest_distance: f32 = distance(best);
+    for code in 17..=255 {
+        let color = AnsiColor { code };
+        let distance = distance(color);
+        if distance < smallest_distance {
+            best = color;
+            smallest_distance = distance;
+        }
+    }
+    best
+}
+
+/// check that all metrics bring back an ANSI color to itself
+#[test]
+fn test_ansi_to_rgb_to_ansi_with() {
+    for code in 16..=255 {
+        let c1 = AnsiColor { code };
+        let rgb = c1.to_rgb();
+        assert_eq!(c1, rgb.to_ansi_with::<RedmeanDistance>());
+        assert_eq!(c1, rgb.to_ansi_with::<RgbDistance>());
+        assert_eq!(c1, rgb.to_ansi_with::<Ciede2000Distance>());
+        assert_eq!(c1, rgb.to_ansi_with::<OklabDistance>());
+    }
+    // unlike to_ansi, the search with RedmeanDistance is exhaustive
+    let rgb = Rgb::new(0, 0, 5);
+    assert_eq!(rgb.to_ansi_with::<RedmeanDistance>().code, 16);
+    for r in (0..=255).step_by(15) {
+        for g in (0..=255).step_by(15) {
+            for b in (0..=255).step_by(15) {
+                let rgb = Rgb::new(r, g, b);
+                let nearest = rgb.to_ansi_with::<RedmeanDistance>().to_rgb();
+                assert!(rgb.distance_to(nearest) <= rgb.distance_to(rgb.to_ansi().to_rgb()));
+            }
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use crate::*;
+
+/// A timing function, mapping the progress of a transition
+/// to the weight of its destination color
+///
+/// The named easings are the ones of CSS.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Easing {
+    #[default]
+    Linear,
+    /// slow start, like `cubic-bezier(0.42, 0, 1, 1)`
+    EaseIn,
+    /// slow end, like `cubic-bezier(0, 0, 0.58, 1)`
+    EaseOut,
+    /// slow start and end, like `cubic-bezier(0.42, 0, 0.58, 1)`
+    EaseInOut,
+    /// a cubic Bézier curve from `(0,0)` to `(1,1)`, defined by the
+    /// coordinates `(x1, y1, x2, y2)` of its two control points,
+    /// `x1` and `x2` being in `[0,1]`
+    CubicBezier(f32, f32, f32, f32),
+    /// a staircase of the given number of steps, each step
+    /// being at the end of its interval, like `steps(n, end)`
+    Steps(u16),
+}
+
+impl Easing {
+    /// Return the eased value of `t`, which is clamped to `[0,1]`
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => cubic_bezier(0.42, 0.0, 1.0, 1.0, t),
+            Self::EaseOut => cubic_bezier(0.0, 0.0, 0.58, 1.0, t),
+            Self::EaseInOut => cubic_bezier(0.42, 0.0, 0.58, 1.0, t),
+            Self::CubicBezier(x1, y1, x2, y2) => {
+                cubic_bezier(x1.clamp(0.0, 1.0), y1, x2.clamp(0.0, 1.0), y2, t)
+            }
+            Self::Steps(0) => t,
+            Self::Steps(n) => {
+                let n = n as f32;
+                libm::floorf(t * n) / n
+            }
+        }
+    }
+}
+
+/// Compute the y of the cubic Bézier curve at the given x
+fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32, x: f32) -> f32 {
+    // coordinate of the curve at parameter s, for control points p1 and p2
+    let curve = |p1: f32, p2: f32, s: f32| {
+        let r = 1.0 - s;
+        3.0 * r * r * s * p1 + 3.0 * r * s * s * p2 + s * s * s
+    };
+    // x is monotonic in s as x1 and x2 are in [0,1], so bisection is safe
+    let (mut min, mut max) = (0.0, 1.0);
+    for _ in 0..24 {
+        let s = (min + max) / 2.0;
+        if curve(x1, x2, s) < x {
+            min = s;
+        } else {
+            max = s;
+        }
+    }
+    curve(y1, y2, (min + max) / 2.0)
+}
+
+impl Color {
+    /// Blend two colors like [Color::blend], `t` in `[0,1]` being the
+    /// progress of a transition from `c1` to `c2`, eased by `easing`
+    ///
+    /// Colors can't overshoot, so an easing going out of `[0,1]`,
+    /// like some cubic Bézier curves, is clamped.
+    pub fn blend_eased<C1: Into<Color>, C2: Into<Color>>(
+        c1: C1,
+        c2: C2,
+        t: f32,
+        easing: Easing,
+    ) -> Self {
+        let w2 = easing.apply(t).clamp(0.0, 1.0);
+        Self::blend(c1, 1.0 - w2, c2, w2)
+    }
+}
+
+#[test]
+fn test_easing() {
+    let easings = [
+        Easing::Linear,
+        Easing::EaseIn,
+        Easing::EaseOut,
+        Easing::EaseInOut,
+        Easing::CubicBezier(0.3, -0.5, 0.7, 1.5),
+        Easing::Steps(4),
+    ];
+    for easing in easings {
+        assert!(easing.apply(0.0).abs() < 0.001);
+        assert!((easing.apply(1.0) - 1.0).abs() < 0.001);
+    }
+    assert!(Easing::EaseIn.apply(0.5) < 0.4);
+    assert!(Easing::EaseOut.apply(0.5) > 0.6);
+    assert!((Easing::EaseInOut.apply(0.5) - 0.5).abs() < 0.001);
+    assert_eq!(Easing::Steps(4).apply(0.6), 0.5);
+    // the reference value of CSS's ease-in at 0.25 is about 0.0935
+    assert!((Easing::EaseIn.apply(0.25) - 0.0935).abs() < 0.001);
+    // an overshooting curve doesn't make blending fail
+    let overshooting = Easing::CubicBezier(0.3, -0.5, 0.7, 1.5);
+    assert!(overshooting.apply(0.1) < 0.0 && overshooting.apply(0.9) > 1.0);
+    let (c1, c2) = (Rgb::new(10, 200, 30), Rgb::new(250, 20, 20));
+    for i in 0..=20 {
+        Color::blend_eased(c1, c2, i as f32 / 20.0, overshooting);
+    }
+    assert_eq!(Color::blend_eased(c1, c2, 0.9, overshooting).rgb(), c2);
+}
@@ -1,4 +1,79 @@
+use core::{
+    fmt,
+    ops::RangeBounds,
+};
+
 /// coolor error type
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum CoolorError {
+    /// HSL components out of their ranges
     InvalidHsl(f32, f32, f32),
+    /// A component of a color out of its range
+    OutOfRange {
+        component: &'static str,
+        value: f32,
+    },
+    /// A hexadecimal color which isn't made of 3, 4, 6 or 8 hexadecimal
+    /// digits, the problem being at the given byte position
+    InvalidHex(usize),
+    /// A color name which isn't known, starting at the given byte position
+    UnknownName(usize),
+    /// The string couldn't be parsed as a color,
+    /// the problem being at the given byte position
+    InvalidColorString(usize),
+    /// A fixed capacity collection is full
+    CapacityExceeded(usize),
+    /// At least one color is needed
+    NoColor,
+    /// The colors couldn't be made to differ by at least
+    /// the given CIEDE2000 difference
+    NotDistinct(f32),
 }
+
+impl CoolorError {
+    /// Check a component is in the given range
+    pub(crate) fn check<R: RangeBounds<f32>>(
+        component: &'static str,
+        value: f32,
+        range: R,
+    ) -> Result<(), Self> {
+        if range.contains(&value) {
+            Ok(())
+        } else {
+            Err(Self::OutOfRange { component, value })
+        }
+    }
+}
+
+impl fmt::Display for CoolorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidHsl(h, s, l) => {
+                write!(f, "invalid HSL color (h={h}, s={s}, l={l})")
+            }
+            Self::OutOfRange { component, value } => {
+                write!(f, "color component {component} out of range: {value}")
+            }
+            Self::InvalidHex(pos) => {
+                write!(f, "invalid hexadecimal color at position {pos}")
+            }
+            Self::UnknownName(pos) => {
+                write!(f, "unknown color name at position {pos}")
+            }
+            Self::InvalidColorString(pos) => {
+                write!(f, "invalid color at position {pos}")
+            }
+            Self::CapacityExceeded(capacity) => {
+                write!(f, "capacity of {capacity} exceeded")
+            }
+            Self::NoColor => {
+                write!(f, "at least one color is needed")
+            }
+            Self::NotDistinct(min_delta_e) => {
+                write!(f, "colors can't be made to differ by a ΔE of {min_delta_e}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for CoolorError {}
@@ -0,0 +1,207 @@
+use {
+    crate::*,
+    core::fmt::{
+        self,
+        Write,
+    },
+};
+
+// All the formats written here can be parsed back with `FromStr`.
+
+/// A small buffer, to check what a number is parsed back to
+/// without allocating
+#[derive(Default)]
+struct Buffer {
+    bytes: [u8; 32],
+    len: usize,
+}
+
+impl fmt::Write for Buffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.bytes.len() {
+            return Err(fmt::Error);
+        }
+        self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Write a value of `[0,1]` as a percentage, with the fewest
+/// decimals giving back the same value when parsed
+fn write_percentage(f: &mut fmt::Formatter<'_>, v: f32) -> fmt::Result {
+    let v = v.clamp(0.0, 1.0);
+    // exact, as a f32 times 100 fits in the mantissa of a f64
+    let p = v as f64 * 100.0;
+    for precision in 0..=8 {
+        let mut buffer = Buffer::default();
+        if write!(buffer, "{:.*}", precision, p).is_err() {
+            break;
+        }
+        let parsed = core::str::from_utf8(&buffer.bytes[..buffer.len])
+            .ok()
+            .and_then(|s| s.parse::<f64>().ok());
+        if parsed.is_some_and(|parsed| (parsed / 100.0) as f32 == v) {
+            return write!(f, "{:.*}%", precision, p);
+        }
+    }
+    write!(f, "{}%", p)
+}
+
+impl fmt::Display for Rgb {
+    /// Write the color as `#rrggbb`, or as `rgb(r g b)` with the
+    /// alternate flag (`{:#}`)
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "rgb({} {} {})", self.r, self.g, self.b)
+        } else {
+            write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+        }
+    }
+}
+
+impl fmt::Display for Hsl {
+    /// Write the color as `hsl(h s% l%)`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "hsl({} ", self.h)?;
+        write_percentage(f, self.s)?;
+        f.write_str(" ")?;
+        write_percentage(f, self.l)?;
+        f.write_str(")")
+    }
+}
+
+impl fmt::Display for Rgba {
+    /// Write the color as `#rrggbbaa`, or as `rgb(r g b / a)` with the
+    /// alternate flag (`{:#}`)
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "rgb({} {} {} / {})", self.r, self.g, self.b, self.alpha())
+        } else {
+            write!(f, "#{:02x}{:02x}{:02x}{:02x}", self.r, self.g, self.b, self.a)
+        }
+    }
+}
+
+impl fmt::Display for Hsla {
+    /// Write the color as `hsl(h s% l% / a)`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "hsl({} ", self.h)?;
+        write_percentage(f, self.s)?;
+        f.write_str(" ")?;
+        write_percentage(f, self.l)?;
+        write!(f, " / {})", self.a)
+    }
+}
+
+impl fmt::Display for AnsiColor {
+    /// Write the color as `ansi(n)`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ansi({})", self.code)
+    }
+}
+
+impl fmt::Display for Lab {
+    /// Write the color as `lab(l a b)`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "lab({} {} {})", self.l, self.a, self.b)
+    }
+}
+
+impl fmt::Display for Lch {
+    /// Write the color as `lch(l c h)`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "lch({} {} {})", self.l, self.c, self.h)
+    }
+}
+
+impl fmt::Display for Oklab {
+    /// Write the color as `oklab(l a b)`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "oklab({} {} {})", self.l, self.a, self.b)
+    }
+}
+
+impl fmt::Display for Oklch {
+    /// Write the color as `oklch(l c h)`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "oklch({} {} {})", self.l, self.c, self.h)
+    }
+}
+
+impl fmt::Display for Color {
+    /// Write the color in the syntax of its variant, eg `#rrggbb`
+    /// for a RGB color or `ansi(n)` for an ANSI one.
+    ///
+    /// The alternate flag (`{:#}`) is passed to the inner color.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ansi(ansi) => fmt::Display::fmt(ansi, f),
+            Self::Hsl(hsl) => fmt::Display::fmt(hsl, f),
+            Self::Rgb(rgb) => fmt::Display::fmt(rgb, f),
+            Self::Lab(lab) => fmt::Display::fmt(lab, f),
+            Self::Lch(lch) => fmt::Display::fmt(lch, f),
+            Self::Oklab(oklab) => fmt::Display::fmt(oklab, f),
+            Self::Oklch(oklch) => fmt::Display::fmt(oklch, f),
+        }
+    }
+}
+
+#[cfg(test)]
+use std::format;
+
+#[test]
+fn test_format() {
+    let rgb = Rgb::new(255, 136, 0);
+    assert_eq!(format!("{}", rgb), "#ff8800");
+    assert_eq!(format!("{:#}", rgb), "rgb(255 136 0)");
+    let hsl = Hsl::new(120.0, 0.5, 0.25);
+    assert_eq!(format!("{}", hsl), "hsl(120 50% 25%)");
+    let ansi = Color::Ansi(AnsiColor::new(42));
+    assert_eq!(format!("{}", ansi), "ansi(42)");
+}
+/// check formatting then parsing gives back the same color
+#[test]
+fn test_format_then_parse() {
+    for code in 0..=255 {
+        let ansi = AnsiColor::new(code);
+        let rgb = ansi.to_rgb();
+        let colors = [
+            Color::Ansi(ansi),
+            Color::Rgb(rgb),
+            Color::Lab(rgb.to_lab()),
+            Color::Lch(rgb.to_lch()),
+            Color::Oklab(rgb.to_oklab()),
+            Color::Oklch(rgb.to_oklch()),
+        ];
+        for color in colors {
+            let parsed: Color = format!("{}", color).parse().unwrap();
+            assert_eq!(color, parsed);
+        }
+        let parsed: Rgb = format!("{:#}", rgb).parse().unwrap();
+        assert_eq!(rgb, parsed);
+        let rgba = rgb.with_alpha(code as f32 / 255.0);
+        assert_eq!(rgba, format!("{}", rgba).parse().unwrap());
+        assert_eq!(rgba, format!("{:#}", rgba).parse().unwrap());
+    }
+}
+/// check HSL colors are written back losslessly
+#[test]
+fn test_format_then_parse_hsl() {
+    for r in (0..=255).step_by(7) {
+        for g in (0..=255).step_by(7) {
+            for b in (0..=255).step_by(5) {
+                let hsl = Rgb::new(r, g, b).to_hsl();
+                assert_eq!(hsl, format!("{}", hsl).parse().unwrap());
+                let color = Color::Hsl(hsl);
+                assert_eq!(color, format!("{}", color).parse().unwrap());
+                let hsla = hsl.with_alpha(b as f32 / 255.0);
+                assert_eq!(hsla, format!("{}", hsla).parse().unwrap());
+            }
+        }
+    }
+    let hsl = Hsl::new(0.001, 0.000123, 0.3);
+    assert_eq!(format!("{}", hsl), "hsl(0.001 0.0123% 30%)");
+    assert_eq!(hsl, format!("{}", hsl).parse().unwrap());
+}
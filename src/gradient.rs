@@ -0,0 +1,236 @@
+use crate::*;
+
+/// The way to go around the hue circle when interpolating hues
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HuePath {
+    /// the shortest arc, never more than 180°
+    #[default]
+    Shorter,
+    /// the longest arc, at least 180°, and a full turn for equal hues
+    Longer,
+}
+
+/// The color space in which a [Gradient] interpolates
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InterpolationSpace {
+    /// gamma-encoded sRGB, like [Rgb::mix]
+    Rgb,
+    /// linear light, like [Rgb::mix_linear]
+    LinearRgb,
+    Hsl(HuePath),
+    /// OKLab, like [Color::blend_oklab]
+    Oklab,
+    Oklch(HuePath),
+}
+
+/// A color at a position in a [Gradient]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColorStop {
+    /// position in `[0,1]`
+    pub position: f32,
+    pub color: Color,
+}
+
+impl ColorStop {
+    pub const fn new(position: f32, color: Color) -> Self {
+        Self { position, color }
+    }
+}
+
+/// A succession of colors, interpolated between stops
+///
+/// ```
+/// use coolor::*;
+/// let stops = [
+///     ColorStop::new(0.0, Rgb::new(200, 30, 30).into()),
+///     ColorStop::new(0.6, Rgb::new(250, 200, 50).into()),
+///     ColorStop::new(1.0, Rgb::new(40, 180, 60).into()),
+/// ];
+/// let gradient = Gradient::new(&stops, InterpolationSpace::Oklab).unwrap();
+/// assert_eq!(gradient.sample(0.6).rgb(), Rgb::new(250, 200, 50));
+/// let bar: Vec<AnsiColor> = gradient.ansi_iter(30).collect();
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Gradient<'s> {
+    stops: &'s [ColorStop],
+    space: InterpolationSpace,
+    easing: Easing,
+}
+
+impl<'s> Gradient<'s> {
+    /// Build a gradient, checking there's at least one stop and
+    /// that positions are in `[0,1]` and in increasing order
+    pub fn new(stops: &'s [ColorStop], space: InterpolationSpace) -> Result<Self, CoolorError> {
+        if stops.is_empty() {
+            return Err(CoolorError::NoColor);
+        }
+        let mut min = 0.0;
+        for stop in stops {
+            CoolorError::check("position", stop.position, min..=1.0)?;
+            min = stop.position;
+        }
+        Ok(Self {
+            stops,
+            space,
+            easing: Easing::Linear,
+        })
+    }
+    /// Return the same gradient with the easing applied to
+    /// the positions given to [Gradient::sample]
+    pub fn with_easing(self, easing: Easing) -> Self {
+        Self { easing, ..self }
+    }
+    pub fn stops(&self) -> &'s [ColorStop] {
+        self.stops
+    }
+    pub fn space(&self) -> InterpolationSpace {
+        self.space
+    }
+    pub fn easing(&self) -> Easing {
+        self.easing
+    }
+    /// Return the color at position `t`, which is clamped to `[0,1]`
+    /// then eased
+    pub fn sample(&self, t: f32) -> Color {
+        let t = self.easing.apply(t);
+        let first = self.stops[0];
+        if t.is_nan() || t <= first.position {
+            return first.color;
+        }
+        for pair in self.stops.windows(2) {
+            let (s1, s2) = (pair[0], pair[1]);
+            if t <= s2.position {
+                let w2 = (t - s1.position) / (s2.position - s1.position);
+                return self.space.mix(s1.color, s2.color, w2);
+            }
+        }
+        self.stops[self.stops.len() - 1].color
+    }
+    /// Iterate over `n` colors evenly spaced over the gradient,
+    /// from the first stop to the last one
+    pub fn iter(&self, n: usize) -> impl Iterator<Item = Color> + '_ {
+        let intervals = n.saturating_sub(1).max(1) as f32;
+        (0..n).map(move |i| self.sample(i as f32 / intervals))
+    }
+    /// Iterate over the ANSI colors of `n` samples, skipping
+    /// the ones equal to the previous one
+    pub fn ansi_iter(&self, n: usize) -> impl Iterator<Item = AnsiColor> + '_ {
+        let mut previous = None;
+        self.iter(n)
+            .map(Color::ansi)
+            .filter(move |&ansi| previous.replace(ansi) != Some(ansi))
+    }
+}
+
+impl InterpolationSpace {
+    /// Interpolate between two colors, `t` being the
+    /// weight of the second one, in `[0,1]`
+    pub fn mix(self, c1: Color, c2: Color, t: f32) -> Color {
+        let (w1, w2) = (1.0 - t, t);
+        match self {
+            Self::Rgb => Rgb::mix(c1.rgb(), w1, c2.rgb(), w2).into(),
+            Self::LinearRgb => Rgb::mix_linear(c1.rgb(), w1, c2.rgb(), w2).into(),
+            Self::Hsl(path) => {
+                let (hsl1, hsl2) = (c1.hsl(), c2.hsl());
+                // like in OKLCh, near greys have a powerless hue
+                let hue = |hsl: Hsl| (hsl.s >= 1e-4).then_some(hsl.h);
+                Hsl {
+                    h: path.mix(hue(hsl1), hue(hsl2), t).unwrap_or(hsl1.h),
+                    s: w1 * hsl1.s + w2 * hsl2.s,
+                    l: w1 * hsl1.l + w2 * hsl2.l,
+                }
+                .into()
+            }
+            Self::Oklab => Oklab::mix(c1.oklab(), w1, c2.oklab(), w2).into(),
+            Self::Oklch(path) => {
+                let (lch1, lch2) = (c1.oklch(), c2.oklch());
+                Oklch {
+                    l: w1 * lch1.l + w2 * lch2.l,
+                    c: w1 * lch1.c + w2 * lch2.c,
+                    h: path.mix(lch1.hue(), lch2.hue(), t).unwrap_or(lch1.h),
+                }
+                .into()
+            }
+        }
+    }
+}
+
+impl HuePath {
+    /// Interpolate between two hues, in degrees, following CSS Color 4.
+    ///
+    /// The hue of an achromatic color, given as `None`, is
+    /// powerless, so the other one is kept all along, even with
+    /// [HuePath::Longer]. There's no hue when both are achromatic.
+    fn mix(self, h1: Option<f32>, h2: Option<f32>, t: f32) -> Option<f32> {
+        let powerless = h1.is_none() || h2.is_none();
+        let (h1, h2) = hues_to_mix(h1, h2)?;
+        if powerless {
+            return Some(h1);
+        }
+        // equal hues make a full turn with the longer path
+        let d = h2 - h1;
+        let (h1, h2) = match self {
+            Self::Shorter if d > 180.0 => (h1 + 360.0, h2),
+            Self::Shorter if d < -180.0 => (h1, h2 + 360.0),
+            Self::Longer if 0.0 < d && d < 180.0 => (h1 + 360.0, h2),
+            Self::Longer if -180.0 < d && d <= 0.0 => (h1, h2 + 360.0),
+            _ => (h1, h2),
+        };
+        Some((h1 + (h2 - h1) * t) % 360.0)
+    }
+}
+
+#[test]
+fn test_gradient_sample() {
+    let red = Color::Rgb(Rgb::new(255, 0, 0));
+    let blue = Color::Rgb(Rgb::new(0, 0, 255));
+    let stops = [ColorStop::new(0.2, red), ColorStop::new(0.8, blue)];
+    let gradient = Gradient::new(&stops, InterpolationSpace::Hsl(HuePath::Shorter)).unwrap();
+    assert_eq!(gradient.sample(0.0), red);
+    assert_eq!(gradient.sample(1.0), blue);
+    assert_eq!(gradient.sample(0.5).rgb(), Rgb::new(255, 0, 255));
+    let gradient = Gradient::new(&stops, InterpolationSpace::Hsl(HuePath::Longer)).unwrap();
+    assert_eq!(gradient.sample(0.5).rgb(), Rgb::new(0, 255, 0));
+    let reds = [ColorStop::new(0.0, red), ColorStop::new(1.0, red)];
+    let gradient = Gradient::new(&reds, InterpolationSpace::Hsl(HuePath::Longer)).unwrap();
+    assert_eq!(gradient.sample(0.5).rgb(), Rgb::new(0, 255, 255));
+    // going from white or black to a color doesn't cross other hues
+    for grey in [Rgb::new(255, 255, 255), Rgb::new(0, 0, 0)] {
+        let stops = [ColorStop::new(0.0, grey.into()), ColorStop::new(1.0, blue)];
+        for path in [HuePath::Shorter, HuePath::Longer] {
+            for space in [InterpolationSpace::Oklch(path), InterpolationSpace::Hsl(path)] {
+                let gradient = Gradient::new(&stops, space).unwrap();
+                for color in gradient.iter(9) {
+                    let rgb = color.rgb();
+                    assert!(rgb.r <= rgb.b && rgb.g <= rgb.b, "{rgb:?} in {space:?}");
+                }
+            }
+        }
+        // nor between two greys
+        let stops = [ColorStop::new(0.0, grey.into()), ColorStop::new(1.0, grey.into())];
+        let gradient = Gradient::new(&stops, InterpolationSpace::Oklch(HuePath::Longer)).unwrap();
+        assert_eq!(gradient.sample(0.5).rgb(), grey);
+    }
+    let gradient = Gradient::new(&stops, InterpolationSpace::Rgb).unwrap();
+    assert_eq!(gradient.sample(0.5).rgb(), Rgb::new(128, 0, 128));
+    assert_eq!(gradient.iter(5).count(), 5);
+    let gradient = gradient.with_easing(Easing::Steps(2));
+    assert_eq!(gradient.sample(0.45), red);
+    assert!(Gradient::new(&stops[..0], InterpolationSpace::Rgb).is_err());
+    let unordered = [stops[1], stops[0]];
+    assert!(Gradient::new(&unordered, InterpolationSpace::Rgb).is_err());
+}
+#[test]
+fn test_gradient_ansi_iter() {
+    let stops = [
+        ColorStop::new(0.0, AnsiColor::new(22).into()),
+        ColorStop::new(1.0, AnsiColor::new(46).into()),
+    ];
+    let gradient = Gradient::new(&stops, InterpolationSpace::Oklab).unwrap();
+    let mut previous = None;
+    for ansi in gradient.ansi_iter(100) {
+        assert_ne!(Some(ansi), previous);
+        previous = Some(ansi);
+    }
+    assert_eq!(previous, Some(AnsiColor::new(46)));
+}
@@ -13,6 +13,7 @@ pub struct Hsl {
 
 impl Hsl {
     /// Create a new HSL color from its components
+    #[allow(clippy::manual_range_contains)]
     pub fn new(h: f32, s: f32, l: f32) -> Self {
         debug_assert!(h >= 0.0 && h < 360.0);
         debug_assert!(s >= 0.0 && s <= 1.0);
@@ -20,8 +21,9 @@ impl Hsl {
         Self { h, s, l }
     }
     /// Create a new HSL color from its components, checking the ranges
+    #[allow(clippy::manual_range_contains)]
     pub fn checked(h: f32, s: f32, l: f32) -> Result<Self, CoolorError> {
-        if !(h >= 0.0 && h < 360.0 && s >= 0.0 && s <= 1.0 && l >= 0.0 && l <= 1.0) {
+        if h >= 0.0 && h < 360.0 && s >= 0.0 && s <= 1.0 && l >= 0.0 && l <= 1.0 {
             Ok(Self { h, s, l })
         } else {
             Err(CoolorError::InvalidHsl(h, s, l))
@@ -50,6 +52,8 @@ impl Hsl {
     ///
     /// This is a slow function as it literally tries all
     /// ANSI colors and picks the nearest one.
+    /// `self.to_rgb().to_ansi()` is much faster, and usually
+    /// gives the same result.
     pub fn to_ansi(self) -> AnsiColor {
         let mut best = AnsiColor { code: 16 };
         let mut smallest_distance: f32 = self.distance_to(best);
@@ -63,6 +67,10 @@ impl Hsl {
         }
         best
     }
+    /// Return the nearest ANSI color according to the given metric
+    pub fn to_ansi_with<M: ColorDistance>(self) -> AnsiColor {
+        self.to_rgb().to_ansi_with::<M>()
+    }
     pub fn to_rgb(self) -> Rgb {
         let h = self.h / 360.0;
         let s = self.s;
@@ -140,3 +148,11 @@ fn dist(a: f32, b: f32) -> f32 {
         a - b
     }
 }
+
+#[test]
+fn test_checked() {
+    assert_eq!(Hsl::checked(120.0, 0.5, 0.25), Ok(Hsl::new(120.0, 0.5, 0.25)));
+    assert!(Hsl::checked(360.0, 0.5, 0.25).is_err());
+    assert!(Hsl::checked(120.0, 1.5, 0.25).is_err());
+    assert!(Hsl::checked(120.0, 0.5, f32::NAN).is_err());
+}
@@ -0,0 +1,71 @@
+use crate::*;
+
+/// HSL color with an alpha channel
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hsla {
+    /// hue in `[0,360[`
+    pub h: f32,
+    /// saturation in `[0,1]`
+    pub s: f32,
+    /// luminosity in `[0,1]`
+    pub l: f32,
+    /// alpha (opacity) in `[0,1]`, 0 being fully transparent
+    pub a: f32,
+}
+
+impl Hsla {
+    /// Create a new HSLA color from its components
+    pub fn new(h: f32, s: f32, l: f32, a: f32) -> Self {
+        debug_assert!((0.0..360.0).contains(&h));
+        debug_assert!((0.0..=1.0).contains(&s));
+        debug_assert!((0.0..=1.0).contains(&l));
+        debug_assert!((0.0..=1.0).contains(&a));
+        Self { h, s, l, a }
+    }
+    /// Create a new HSLA color from its components, checking the ranges
+    pub fn checked(h: f32, s: f32, l: f32, a: f32) -> Result<Self, CoolorError> {
+        Hsl::checked(h, s, l)?;
+        CoolorError::check("a", a, 0.0..=1.0)?;
+        Ok(Self { h, s, l, a })
+    }
+    /// The color without its transparency
+    pub const fn hsl(self) -> Hsl {
+        Hsl {
+            h: self.h,
+            s: self.s,
+            l: self.l,
+        }
+    }
+    pub fn to_rgba(self) -> Rgba {
+        self.hsl().to_rgb().with_alpha(self.a)
+    }
+    /// Compute the color seen when this color is drawn over
+    /// an opaque background, see [Rgba::over]
+    pub fn over<B: Into<Rgb>>(self, background: B) -> Rgb {
+        self.to_rgba().over(background)
+    }
+    /// Return the nearest ANSI color of the color seen when this color
+    /// is drawn over an opaque background
+    pub fn over_ansi<B: Into<Rgb>>(self, background: B) -> AnsiColor {
+        self.over(background).to_ansi()
+    }
+}
+
+impl Hsl {
+    /// Return the same color with the given opacity, in `[0,1]`
+    pub fn with_alpha(self, a: f32) -> Hsla {
+        Hsla::new(self.h, self.s, self.l, a)
+    }
+}
+
+impl From<Hsl> for Hsla {
+    /// Convert to a fully opaque color
+    fn from(hsl: Hsl) -> Self {
+        hsl.with_alpha(1.0)
+    }
+}
+impl From<Rgba> for Hsla {
+    fn from(rgba: Rgba) -> Self {
+        rgba.to_hsla()
+    }
+}
@@ -0,0 +1,132 @@
+use crate::*;
+
+/// reference white (D65, 2° observer)
+const XN: f32 = 0.950_47;
+const YN: f32 = 1.0;
+const ZN: f32 = 1.088_83;
+
+/// CIELAB color, relative to the D65 white point
+///
+/// See <https://en.wikipedia.org/wiki/CIELAB_color_space>
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Lab {
+    /// lightness in `[0,100]`
+    pub l: f32,
+    /// green-red axis, roughly in `[-128,128]`
+    pub a: f32,
+    /// blue-yellow axis, roughly in `[-128,128]`
+    pub b: f32,
+}
+
+impl Lab {
+    /// Create a new Lab color from its components
+    pub const fn new(l: f32, a: f32, b: f32) -> Self {
+        Self { l, a, b }
+    }
+    /// Create a new Lab color from its components, checking
+    /// the lightness is in `[0,100]` and the axis are finite
+    pub fn checked(l: f32, a: f32, b: f32) -> Result<Self, CoolorError> {
+        CoolorError::check("l", l, 0.0..=100.0)?;
+        CoolorError::check("a", a, f32::MIN..=f32::MAX)?;
+        CoolorError::check("b", b, f32::MIN..=f32::MAX)?;
+        Ok(Self { l, a, b })
+    }
+    /// Return the nearest sRGB color, clamping the colors
+    /// which aren't in the sRGB gamut
+    pub fn to_rgb(self) -> Rgb {
+        let fy = (self.l + 16.0) / 116.0;
+        let fx = fy + self.a / 500.0;
+        let fz = fy - self.b / 200.0;
+        let x = XN * f_inv(fx);
+        let y = YN * f_inv(fy);
+        let z = ZN * f_inv(fz);
+        let r = 3.240_454_2 * x - 1.537_138_5 * y - 0.498_531_4 * z;
+        let g = -0.969_266 * x + 1.876_010_8 * y + 0.041_556 * z;
+        let b = 0.055_643_4 * x - 0.204_025_9 * y + 1.057_225_2 * z;
+        LinearRgb::new(r, g, b).to_rgb()
+    }
+    pub fn to_hsl(self) -> Hsl {
+        self.to_rgb().to_hsl()
+    }
+    /// Return the nearest ANSI color
+    pub fn to_ansi(self) -> AnsiColor {
+        self.to_rgb().to_ansi()
+    }
+    /// Convert to the cylindrical representation of the same color
+    pub fn to_lch(self) -> Lch {
+        let c = libm::sqrtf(self.a * self.a + self.b * self.b);
+        let h = libm::atan2f(self.b, self.a).to_degrees();
+        let h = if h < 0.0 { h + 360.0 } else { h };
+        Lch { l: self.l, c, h: h % 360.0 }
+    }
+}
+
+impl From<Rgb> for Lab {
+    fn from(rgb: Rgb) -> Self {
+        let LinearRgb { r, g, b } = rgb.to_linear();
+        let x = 0.412_456_4 * r + 0.357_576_1 * g + 0.180_437_5 * b;
+        let y = 0.212_672_9 * r + 0.715_152_2 * g + 0.072_175 * b;
+        let z = 0.019_333_9 * r + 0.119_192 * g + 0.950_304_1 * b;
+        let fx = f(x / XN);
+        let fy = f(y / YN);
+        let fz = f(z / ZN);
+        Self {
+            l: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+        }
+    }
+}
+impl From<Hsl> for Lab {
+    fn from(hsl: Hsl) -> Self {
+        hsl.to_rgb().into()
+    }
+}
+impl From<AnsiColor> for Lab {
+    fn from(ansi: AnsiColor) -> Self {
+        ansi.to_rgb().into()
+    }
+}
+impl From<Lch> for Lab {
+    fn from(lch: Lch) -> Self {
+        lch.to_lab()
+    }
+}
+
+const DELTA: f32 = 6.0 / 29.0;
+
+fn f(t: f32) -> f32 {
+    if t > DELTA * DELTA * DELTA {
+        libm::cbrtf(t)
+    } else {
+        t / (3.0 * DELTA * DELTA) + 4.0 / 29.0
+    }
+}
+
+fn f_inv(t: f32) -> f32 {
+    if t > DELTA {
+        t * t * t
+    } else {
+        3.0 * DELTA * DELTA * (t - 4.0 / 29.0)
+    }
+}
+
+#[test]
+fn test_rgb_to_lab() {
+    let white = Rgb::new(255, 255, 255).to_lab();
+    assert!((white.l - 100.0).abs() < 0.01);
+    assert!(white.a.abs() < 0.01 && white.b.abs() < 0.01);
+    let red = Rgb::new(255, 0, 0).to_lab();
+    assert!((red.l - 53.24).abs() < 0.01);
+    assert!((red.a - 80.09).abs() < 0.01);
+    assert!((red.b - 67.20).abs() < 0.01);
+}
+/// check going from rgb to lab and lch and back makes us fall on the first color
+#[test]
+fn test_rgb_to_lab_to_rgb() {
+    for code in 0..=255 {
+        let rgb = AnsiColor::new(code).to_rgb();
+        assert_eq!(rgb, rgb.to_lab().to_rgb());
+        assert_eq!(rgb, rgb.to_lch().to_rgb());
+    }
+}
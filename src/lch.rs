@@ -0,0 +1,67 @@
+use crate::*;
+
+/// CIELCh color: the cylindrical representation of [Lab]
+///
+/// See <https://en.wikipedia.org/wiki/CIELAB_color_space#Cylindrical_model>
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Lch {
+    /// lightness in `[0,100]`
+    pub l: f32,
+    /// chroma, 0 for greys, up to about 135 in sRGB
+    pub c: f32,
+    /// hue in `[0,360[`
+    pub h: f32,
+}
+
+impl Lch {
+    /// Create a new LCh color from its components
+    pub const fn new(l: f32, c: f32, h: f32) -> Self {
+        Self { l, c, h }
+    }
+    /// Create a new LCh color from its components, checking the ranges
+    pub fn checked(l: f32, c: f32, h: f32) -> Result<Self, CoolorError> {
+        CoolorError::check("l", l, 0.0..=100.0)?;
+        CoolorError::check("c", c, 0.0..=f32::MAX)?;
+        CoolorError::check("h", h, 0.0..360.0)?;
+        Ok(Self { l, c, h })
+    }
+    pub fn to_lab(self) -> Lab {
+        let h = self.h.to_radians();
+        Lab {
+            l: self.l,
+            a: self.c * libm::cosf(h),
+            b: self.c * libm::sinf(h),
+        }
+    }
+    pub fn to_rgb(self) -> Rgb {
+        self.to_lab().to_rgb()
+    }
+    pub fn to_hsl(self) -> Hsl {
+        self.to_rgb().to_hsl()
+    }
+    /// Return the nearest ANSI color
+    pub fn to_ansi(self) -> AnsiColor {
+        self.to_rgb().to_ansi()
+    }
+}
+
+impl From<Lab> for Lch {
+    fn from(lab: Lab) -> Self {
+        lab.to_lch()
+    }
+}
+impl From<Rgb> for Lch {
+    fn from(rgb: Rgb) -> Self {
+        rgb.to_lch()
+    }
+}
+impl From<Hsl> for Lch {
+    fn from(hsl: Hsl) -> Self {
+        hsl.to_rgb().to_lch()
+    }
+}
+impl From<AnsiColor> for Lch {
+    fn from(ansi: AnsiColor) -> Self {
+        ansi.to_rgb().to_lch()
+    }
+}
@@ -1,6 +1,6 @@
 /*!
 
-Definition of ANSI, RGB and HSL color types and all the conversions between them.
+Definition of ANSI, RGB, HSL, CIELAB, CIELCh, OKLab and OKLCh color types and all the conversions between them.
 
 There are many other color conversion crates.
 This one may be useful when you're interested into
@@ -19,16 +19,60 @@ The included example shows luminosity and saturation variants of all 240 ANSI co
 
 #![no_std]
 
+#[cfg(test)]
+extern crate std;
+
 mod ansi;
+mod ansi16;
+mod apca;
+mod audit;
 mod color;
+mod contrast;
+mod deficiency;
+mod delta_e;
+mod distance;
+mod easing;
 mod error;
+mod format;
+mod gradient;
 mod hsl;
+mod hsla;
+mod lab;
+mod lch;
+mod linear_rgb;
+mod names;
+mod oklab;
+mod oklch;
+mod palette;
+mod parse;
 mod rgb;
+mod rgba;
+mod scheme;
+mod tonal_scale;
 
 pub use {
 	ansi::*,
+	ansi16::*,
+	audit::*,
 	color::*,
+	contrast::*,
+	deficiency::*,
+	delta_e::*,
+	distance::*,
+	easing::*,
 	error::*,
+	gradient::*,
 	hsl::*,
+	hsla::*,
+	lab::*,
+	lch::*,
+	linear_rgb::*,
+	names::*,
+	oklab::*,
+	oklch::*,
+	palette::*,
 	rgb::*,
+	rgba::*,
+	scheme::*,
+	tonal_scale::*,
 };
@@ -0,0 +1,97 @@
+use crate::*;
+
+/// RGB color in linear light, with f32 components
+///
+/// Contrary to [Rgb], whose components are gamma-encoded as in
+/// the sRGB standard, the components are proportional to the
+/// light intensity, so that physical operations, like adding
+/// lights, are correct.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LinearRgb {
+    /// red, in `[0,1]`
+    pub r: f32,
+    /// green, in `[0,1]`
+    pub g: f32,
+    /// blue, in `[0,1]`
+    pub b: f32,
+}
+
+impl LinearRgb {
+    /// Create a new linear RGB color from its components
+    pub const fn new(r: f32, g: f32, b: f32) -> Self {
+        Self { r, g, b }
+    }
+    /// Encode the color into sRGB, clamping the
+    /// components outside `[0,1]`
+    pub fn to_rgb(self) -> Rgb {
+        Rgb::new(
+            linear_to_srgb(self.r),
+            linear_to_srgb(self.g),
+            linear_to_srgb(self.b),
+        )
+    }
+    /// Tell whether all components are in `[0,1]`, with a small
+    /// tolerance for rounding errors
+    pub fn is_in_gamut(self) -> bool {
+        const EPSILON: f32 = 0.000_1;
+        let ok = |v: f32| (-EPSILON..=1.0 + EPSILON).contains(&v);
+        ok(self.r) && ok(self.g) && ok(self.b)
+    }
+    pub fn mix(c1: Self, w1: f32, c2: Self, w2: f32) -> Self {
+        debug_assert!(w1 + w2 > 0.0);
+        let r = (w1 * c1.r + w2 * c2.r) / (w1 + w2);
+        let g = (w1 * c1.g + w2 * c2.g) / (w1 + w2);
+        let b = (w1 * c1.b + w2 * c2.b) / (w1 + w2);
+        Self { r, g, b }
+    }
+}
+
+impl From<Rgb> for LinearRgb {
+    fn from(rgb: Rgb) -> Self {
+        Self {
+            r: srgb_to_linear(rgb.r),
+            g: srgb_to_linear(rgb.g),
+            b: srgb_to_linear(rgb.b),
+        }
+    }
+}
+
+/// Decode a gamma-encoded sRGB component into a linear light value in `[0,1]`
+pub(crate) fn srgb_to_linear(v: u8) -> f32 {
+    let v = v as f32 / 255.0;
+    if v <= 0.040_45 {
+        v / 12.92
+    } else {
+        libm::powf((v + 0.055) / 1.055, 2.4)
+    }
+}
+
+/// Encode a linear light value into a sRGB component,
+/// clamping values outside `[0,1]`
+pub(crate) fn linear_to_srgb(v: f32) -> u8 {
+    let v = v.clamp(0.0, 1.0);
+    let v = if v <= 0.003_130_8 {
+        v * 12.92
+    } else {
+        1.055 * libm::powf(v, 1.0 / 2.4) - 0.055
+    };
+    libm::roundf(v * 255.0) as u8
+}
+
+#[test]
+fn test_linear_round_trip() {
+    for v in 0..=255 {
+        assert_eq!(linear_to_srgb(srgb_to_linear(v)), v);
+    }
+    assert_eq!(Rgb::new(255, 255, 255).to_linear(), LinearRgb::new(1.0, 1.0, 1.0));
+}
+#[test]
+fn test_mix_linear() {
+    let black = Rgb::new(0, 0, 0);
+    let white = Rgb::new(255, 255, 255);
+    assert_eq!(Rgb::mix_linear(black, 1.0, white, 1.0), Rgb::new(188, 188, 188));
+    assert_eq!(Rgb::mix_linear(black, 0.0, white, 1.0), white);
+    let red = Rgb::new(255, 0, 0);
+    let green = Rgb::new(0, 255, 0);
+    assert_eq!(Rgb::mix_linear(red, 1.0, green, 1.0), Rgb::new(188, 188, 0));
+}
@@ -0,0 +1,127 @@
+use crate::*;
+
+/// OKLab color
+///
+/// A perceptual color space, where euclidean distances and
+/// linear interpolations are closer to what the eye sees than
+/// in RGB or HSL.
+///
+/// See <https://bottosson.github.io/posts/oklab/>
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Oklab {
+    /// perceived lightness in `[0,1]`
+    pub l: f32,
+    /// green-red axis, roughly in `[-0.4,0.4]`
+    pub a: f32,
+    /// blue-yellow axis, roughly in `[-0.4,0.4]`
+    pub b: f32,
+}
+
+impl Oklab {
+    /// Create a new OKLab color from its components
+    pub const fn new(l: f32, a: f32, b: f32) -> Self {
+        Self { l, a, b }
+    }
+    /// Create a new OKLab color from its components, checking
+    /// the lightness is in `[0,1]` and the axis are finite
+    pub fn checked(l: f32, a: f32, b: f32) -> Result<Self, CoolorError> {
+        CoolorError::check("l", l, 0.0..=1.0)?;
+        CoolorError::check("a", a, f32::MIN..=f32::MAX)?;
+        CoolorError::check("b", b, f32::MIN..=f32::MAX)?;
+        Ok(Self { l, a, b })
+    }
+    /// Return the nearest sRGB color, clamping the colors
+    /// which aren't in the sRGB gamut
+    pub fn to_rgb(self) -> Rgb {
+        self.to_linear().to_rgb()
+    }
+    /// Convert to linear RGB, without clamping: components are
+    /// outside `[0,1]` when the color isn't in the sRGB gamut
+    pub fn to_linear(self) -> LinearRgb {
+        let l = self.l + 0.396_337_78 * self.a + 0.215_803_76 * self.b;
+        let m = self.l - 0.105_561_346 * self.a - 0.063_854_17 * self.b;
+        let s = self.l - 0.089_484_18 * self.a - 1.291_485_5 * self.b;
+        let (l, m, s) = (l * l * l, m * m * m, s * s * s);
+        let r = 4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s;
+        let g = -1.268_438 * l + 2.609_757_4 * m - 0.341_319_4 * s;
+        let b = -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s;
+        LinearRgb::new(r, g, b)
+    }
+    /// Tell whether the color can be displayed in sRGB without clamping
+    pub fn is_in_gamut(self) -> bool {
+        self.to_linear().is_in_gamut()
+    }
+    pub fn to_hsl(self) -> Hsl {
+        self.to_rgb().to_hsl()
+    }
+    /// Return the nearest ANSI color
+    pub fn to_ansi(self) -> AnsiColor {
+        self.to_rgb().to_ansi()
+    }
+    /// Convert to the cylindrical representation of the same color
+    pub fn to_oklch(self) -> Oklch {
+        let c = libm::sqrtf(self.a * self.a + self.b * self.b);
+        let h = libm::atan2f(self.b, self.a).to_degrees();
+        let h = if h < 0.0 { h + 360.0 } else { h };
+        Oklch { l: self.l, c, h: h % 360.0 }
+    }
+    /// Interpolate between two colors. As OKLab is perceptually
+    /// uniform, midpoints don't look muddy like in RGB.
+    pub fn mix(c1: Self, w1: f32, c2: Self, w2: f32) -> Self {
+        debug_assert!(w1 + w2 > 0.0);
+        let l = (w1 * c1.l + w2 * c2.l) / (w1 + w2);
+        let a = (w1 * c1.a + w2 * c2.a) / (w1 + w2);
+        let b = (w1 * c1.b + w2 * c2.b) / (w1 + w2);
+        Self { l, a, b }
+    }
+}
+
+impl From<Rgb> for Oklab {
+    fn from(rgb: Rgb) -> Self {
+        let LinearRgb { r, g, b } = rgb.to_linear();
+        let l = 0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b;
+        let m = 0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b;
+        let s = 0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b;
+        let (l, m, s) = (libm::cbrtf(l), libm::cbrtf(m), libm::cbrtf(s));
+        Self {
+            l: 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            a: 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            b: 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+        }
+    }
+}
+impl From<Hsl> for Oklab {
+    fn from(hsl: Hsl) -> Self {
+        hsl.to_rgb().into()
+    }
+}
+impl From<AnsiColor> for Oklab {
+    fn from(ansi: AnsiColor) -> Self {
+        ansi.to_rgb().into()
+    }
+}
+impl From<Oklch> for Oklab {
+    fn from(oklch: Oklch) -> Self {
+        oklch.to_oklab()
+    }
+}
+
+#[test]
+fn test_rgb_to_oklab() {
+    let white = Rgb::new(255, 255, 255).to_oklab();
+    assert!((white.l - 1.0).abs() < 0.001);
+    assert!(white.a.abs() < 0.001 && white.b.abs() < 0.001);
+    let blue = Rgb::new(0, 0, 255).to_oklab();
+    assert!((blue.l - 0.452).abs() < 0.001);
+    assert!((blue.a + 0.032).abs() < 0.001);
+    assert!((blue.b + 0.312).abs() < 0.001);
+}
+/// check going from rgb to oklab and oklch and back makes us fall on the first color
+#[test]
+fn test_rgb_to_oklab_to_rgb() {
+    for code in 0..=255 {
+        let rgb = AnsiColor::new(code).to_rgb();
+        assert_eq!(rgb, rgb.to_oklab().to_rgb());
+        assert_eq!(rgb, rgb.to_oklch().to_rgb());
+    }
+}
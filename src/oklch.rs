@@ -0,0 +1,142 @@
+use crate::*;
+
+/// OKLCh color: the cylindrical representation of [Oklab]
+///
+/// See <https://bottosson.github.io/posts/oklab/>
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Oklch {
+    /// perceived lightness in `[0,1]`
+    pub l: f32,
+    /// chroma, 0 for greys, up to about 0.32 in sRGB
+    pub c: f32,
+    /// hue in `[0,360[`
+    pub h: f32,
+}
+
+/// Chroma under which the hue of an OKLCh color is powerless, as
+/// greys don't get an exact zero chroma because of rounding errors
+const ACHROMATIC_CHROMA: f32 = 1e-4;
+
+impl Oklch {
+    /// Create a new OKLCh color from its components
+    pub const fn new(l: f32, c: f32, h: f32) -> Self {
+        Self { l, c, h }
+    }
+    /// Create a new OKLCh color from its components, checking the ranges
+    pub fn checked(l: f32, c: f32, h: f32) -> Result<Self, CoolorError> {
+        CoolorError::check("l", l, 0.0..=1.0)?;
+        CoolorError::check("c", c, 0.0..=f32::MAX)?;
+        CoolorError::check("h", h, 0.0..360.0)?;
+        Ok(Self { l, c, h })
+    }
+    pub fn to_oklab(self) -> Oklab {
+        let h = self.h.to_radians();
+        Oklab {
+            l: self.l,
+            a: self.c * libm::cosf(h),
+            b: self.c * libm::sinf(h),
+        }
+    }
+    pub fn to_rgb(self) -> Rgb {
+        self.to_oklab().to_rgb()
+    }
+    pub fn to_hsl(self) -> Hsl {
+        self.to_rgb().to_hsl()
+    }
+    /// Return the nearest ANSI color
+    pub fn to_ansi(self) -> AnsiColor {
+        self.to_rgb().to_ansi()
+    }
+    /// Return the same color with the chroma reduced as much
+    /// as needed for it to be in the sRGB gamut, so that
+    /// lightness and hue are kept
+    pub fn with_chroma_in_gamut(self) -> Self {
+        if self.to_oklab().is_in_gamut() {
+            return self;
+        }
+        let (mut min, mut max) = (0.0, self.c);
+        for _ in 0..16 {
+            let c = (min + max) / 2.0;
+            if (Self { c, ..self }).to_oklab().is_in_gamut() {
+                min = c;
+            } else {
+                max = c;
+            }
+        }
+        Self { c: min, ..self }
+    }
+    /// Return the hue, or `None` when the color is achromatic,
+    /// its hue being then meaningless
+    pub fn hue(self) -> Option<f32> {
+        (self.c >= ACHROMATIC_CHROMA).then_some(self.h)
+    }
+    /// Interpolate between two colors, taking the shortest
+    /// path around the hue circle
+    ///
+    /// The hue of an achromatic color is ignored, so that
+    /// going from white to a color doesn't cross other hues.
+    pub fn mix(c1: Self, w1: f32, c2: Self, w2: f32) -> Self {
+        debug_assert!(w1 + w2 > 0.0);
+        let h = match hues_to_mix(c1.hue(), c2.hue()) {
+            Some((h1, h2)) => {
+                let (h1, h2) = if h2 - h1 > 180.0 {
+                    (h1 + 360.0, h2)
+                } else if h1 - h2 > 180.0 {
+                    (h1, h2 + 360.0)
+                } else {
+                    (h1, h2)
+                };
+                ((w1 * h1 + w2 * h2) / (w1 + w2)) % 360.0
+            }
+            None => c1.h,
+        };
+        let l = (w1 * c1.l + w2 * c2.l) / (w1 + w2);
+        let c = (w1 * c1.c + w2 * c2.c) / (w1 + w2);
+        Self { l, c, h }
+    }
+}
+
+/// Return the hues to interpolate between, a missing (powerless)
+/// hue taking the value of the other one, as in CSS Color 4, or
+/// `None` when both are missing
+pub(crate) fn hues_to_mix(h1: Option<f32>, h2: Option<f32>) -> Option<(f32, f32)> {
+    match (h1, h2) {
+        (Some(h1), Some(h2)) => Some((h1, h2)),
+        (Some(h), None) | (None, Some(h)) => Some((h, h)),
+        (None, None) => None,
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(oklab: Oklab) -> Self {
+        oklab.to_oklch()
+    }
+}
+impl From<Rgb> for Oklch {
+    fn from(rgb: Rgb) -> Self {
+        rgb.to_oklch()
+    }
+}
+impl From<Hsl> for Oklch {
+    fn from(hsl: Hsl) -> Self {
+        hsl.to_rgb().to_oklch()
+    }
+}
+impl From<AnsiColor> for Oklch {
+    fn from(ansi: AnsiColor) -> Self {
+        ansi.to_rgb().to_oklch()
+    }
+}
+
+#[test]
+fn test_mix_achromatic() {
+    let white = Rgb::new(255, 255, 255).to_oklch();
+    let blue = Rgb::new(0, 0, 255).to_oklch();
+    assert_eq!(white.hue(), None);
+    for w in [0.25, 0.5, 0.75] {
+        let mixed = Oklch::mix(white, 1.0 - w, blue, w);
+        assert_eq!(mixed.h, blue.h);
+        let rgb = mixed.to_rgb();
+        assert!(rgb.b > rgb.g && rgb.b > rgb.r, "{rgb:?}");
+    }
+}
@@ -0,0 +1,189 @@
+use crate::*;
+
+/// The RGB colors a terminal displays for the 256 ANSI codes
+///
+/// Terminals usually follow the standard for the 16..=255 range
+/// but their themes redefine the 16 base colors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Palette {
+    pub colors: [Rgb; 256],
+}
+
+impl Palette {
+    /// The palette of [ANSI_TO_RGB], used by [AnsiColor::to_rgb]
+    pub const DEFAULT: Self = Self::new([
+        Rgb::new(0, 0, 0),
+        Rgb::new(128, 0, 0),
+        Rgb::new(0, 128, 0),
+        Rgb::new(128, 128, 0),
+        Rgb::new(0, 0, 128),
+        Rgb::new(128, 0, 128),
+        Rgb::new(0, 128, 128),
+        Rgb::new(192, 192, 192),
+        Rgb::new(128, 128, 128),
+        Rgb::new(255, 0, 0),
+        Rgb::new(0, 255, 0),
+        Rgb::new(255, 255, 0),
+        Rgb::new(0, 0, 255),
+        Rgb::new(255, 0, 255),
+        Rgb::new(0, 255, 255),
+        Rgb::new(255, 255, 255),
+    ]);
+    /// Default colors of xterm
+    pub const XTERM: Self = Self::new([
+        Rgb::new(0, 0, 0),
+        Rgb::new(205, 0, 0),
+        Rgb::new(0, 205, 0),
+        Rgb::new(205, 205, 0),
+        Rgb::new(0, 0, 238),
+        Rgb::new(205, 0, 205),
+        Rgb::new(0, 205, 205),
+        Rgb::new(229, 229, 229),
+        Rgb::new(127, 127, 127),
+        Rgb::new(255, 0, 0),
+        Rgb::new(0, 255, 0),
+        Rgb::new(255, 255, 0),
+        Rgb::new(92, 92, 255),
+        Rgb::new(255, 0, 255),
+        Rgb::new(0, 255, 255),
+        Rgb::new(255, 255, 255),
+    ]);
+    /// Colors of the VGA text mode, also used by the Linux console
+    pub const VGA: Self = Self::new([
+        Rgb::new(0, 0, 0),
+        Rgb::new(170, 0, 0),
+        Rgb::new(0, 170, 0),
+        Rgb::new(170, 85, 0),
+        Rgb::new(0, 0, 170),
+        Rgb::new(170, 0, 170),
+        Rgb::new(0, 170, 170),
+        Rgb::new(170, 170, 170),
+        Rgb::new(85, 85, 85),
+        Rgb::new(255, 85, 85),
+        Rgb::new(85, 255, 85),
+        Rgb::new(255, 255, 85),
+        Rgb::new(85, 85, 255),
+        Rgb::new(255, 85, 255),
+        Rgb::new(85, 255, 255),
+        Rgb::new(255, 255, 255),
+    ]);
+    /// Default colors ("Campbell" scheme) of the Windows console
+    /// since Windows 10
+    pub const WINDOWS_CONSOLE: Self = Self::new([
+        Rgb::new(12, 12, 12),
+        Rgb::new(197, 15, 31),
+        Rgb::new(19, 161, 14),
+        Rgb::new(193, 156, 0),
+        Rgb::new(0, 55, 218),
+        Rgb::new(136, 23, 152),
+        Rgb::new(58, 150, 221),
+        Rgb::new(204, 204, 204),
+        Rgb::new(118, 118, 118),
+        Rgb::new(231, 72, 86),
+        Rgb::new(22, 198, 12),
+        Rgb::new(249, 241, 165),
+        Rgb::new(59, 120, 255),
+        Rgb::new(180, 0, 158),
+        Rgb::new(97, 214, 214),
+        Rgb::new(242, 242, 242),
+    ]);
+    /// Default colors of the macOS Terminal application
+    pub const MACOS_TERMINAL: Self = Self::new([
+        Rgb::new(0, 0, 0),
+        Rgb::new(153, 0, 0),
+        Rgb::new(0, 166, 0),
+        Rgb::new(153, 153, 0),
+        Rgb::new(0, 0, 178),
+        Rgb::new(178, 0, 178),
+        Rgb::new(0, 166, 178),
+        Rgb::new(191, 191, 191),
+        Rgb::new(102, 102, 102),
+        Rgb::new(229, 0, 0),
+        Rgb::new(0, 217, 0),
+        Rgb::new(229, 229, 0),
+        Rgb::new(0, 0, 255),
+        Rgb::new(229, 0, 229),
+        Rgb::new(0, 229, 229),
+        Rgb::new(229, 229, 229),
+    ]);
+    /// Create a palette with the given 16 base colors and the
+    /// standard colors for the 16..=255 range
+    pub const fn new(base: [Rgb; 16]) -> Self {
+        let mut colors = [Rgb::new(0, 0, 0); 256];
+        let mut i = 0;
+        while i < 256 {
+            colors[i] = if i < 16 { base[i] } else { ANSI_TO_RGB[i] };
+            i += 1;
+        }
+        Self { colors }
+    }
+    /// Return the RGB color displayed for an ANSI code
+    pub const fn rgb(&self, ansi: AnsiColor) -> Rgb {
+        self.colors[ansi.code as usize]
+    }
+    /// Return the code of the palette color nearest to the given one.
+    ///
+    /// On equality, codes of the 16..=255 range are preferred, as
+    /// they're less likely to be redefined.
+    pub fn nearest_ansi(&self, rgb: Rgb) -> AnsiColor {
+        let mut best = AnsiColor { code: 16 };
+        let mut smallest_distance: f32 = rgb.distance_to(self.rgb(best));
+        for code in (17..=255).chain(0..16) {
+            let color = AnsiColor { code };
+            let distance = rgb.distance_to(self.rgb(color));
+            if distance < smallest_distance {
+                best = color;
+                smallest_distance = distance;
+            }
+        }
+        best
+    }
+    /// Return the base color of the palette nearest to the given one
+    pub fn nearest_ansi16(&self, rgb: Rgb) -> Ansi16 {
+        let mut best = Ansi16::Black;
+        let mut smallest_distance: f32 = rgb.distance_to(best.rgb_in(self));
+        for &color in &Ansi16::ALL[1..] {
+            let distance = rgb.distance_to(color.rgb_in(self));
+            if distance < smallest_distance {
+                best = color;
+                smallest_distance = distance;
+            }
+        }
+        best
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl Ansi16 {
+    /// Return the RGB color displayed for this color
+    /// by a terminal using the given palette
+    pub const fn rgb_in(self, palette: &Palette) -> Rgb {
+        palette.colors[self as usize]
+    }
+}
+
+#[test]
+fn test_palette_round_trip() {
+    for palette in [
+        Palette::DEFAULT,
+        Palette::XTERM,
+        Palette::VGA,
+        Palette::WINDOWS_CONSOLE,
+        Palette::MACOS_TERMINAL,
+    ] {
+        for code in 0..=255 {
+            let rgb = AnsiColor::new(code).to_rgb_in(&palette);
+            assert_eq!(rgb, rgb.to_ansi_in(&palette).to_rgb_in(&palette));
+        }
+        for c in Ansi16::ALL {
+            assert_eq!(c, c.rgb_in(&palette).to_ansi16_in(&palette));
+        }
+    }
+    assert_eq!(Rgb::new(205, 0, 0).to_ansi_in(&Palette::XTERM).code, 1);
+    assert_eq!(Rgb::new(255, 0, 0).to_ansi_in(&Palette::XTERM).code, 196);
+}
@@ -0,0 +1,403 @@
+use {
+    crate::*,
+    core::str::FromStr,
+};
+
+/// A cursor over the parsed string, keeping the position
+/// for error reporting
+struct Parser<'s> {
+    s: &'s str,
+    pos: usize,
+}
+
+impl<'s> Parser<'s> {
+    fn new(s: &'s str) -> Self {
+        Self { s, pos: 0 }
+    }
+    fn err<T>(&self) -> Result<T, CoolorError> {
+        Err(CoolorError::InvalidColorString(self.pos))
+    }
+    /// return the error built with the current position
+    fn err_with<T, F: Fn(usize) -> CoolorError>(&self, f: F) -> Result<T, CoolorError> {
+        Err(f(self.pos))
+    }
+    fn rest(&self) -> &'s str {
+        &self.s[self.pos..]
+    }
+    fn peek(&self) -> Option<u8> {
+        self.s.as_bytes().get(self.pos).copied()
+    }
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|b| b.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+    /// consume the given char if it's the next one, ignoring whitespaces
+    fn eat(&mut self, c: u8) -> bool {
+        self.skip_whitespace();
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+    fn expect(&mut self, c: u8) -> Result<(), CoolorError> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            self.err()
+        }
+    }
+    /// consume the separator between two function arguments
+    /// (a comma or just whitespaces)
+    fn separator(&mut self) {
+        self.eat(b',');
+    }
+    fn end(&mut self) -> Result<(), CoolorError> {
+        self.skip_whitespace();
+        if self.pos == self.s.len() {
+            Ok(())
+        } else {
+            self.err()
+        }
+    }
+    fn take_while<F: Fn(u8) -> bool>(&mut self, f: F) -> &'s str {
+        let start = self.pos;
+        while self.peek().is_some_and(&f) {
+            self.pos += 1;
+        }
+        &self.s[start..self.pos]
+    }
+    fn identifier(&mut self) -> &'s str {
+        self.skip_whitespace();
+        self.take_while(|b| b.is_ascii_alphanumeric() || b == b'-')
+    }
+    fn number<T: FromStr>(&mut self) -> Result<T, CoolorError> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let digits = self.take_while(|b| b.is_ascii_digit() || b == b'.' || b == b'-' || b == b'+');
+        match digits.parse() {
+            Ok(v) => Ok(v),
+            Err(_) => {
+                self.pos = start;
+                self.err()
+            }
+        }
+    }
+    /// parse a number which, if followed by `%`, is divided
+    /// by 100 then multiplied by `percent_scale`
+    fn number_or_percentage(&mut self, percent_scale: f32) -> Result<f32, CoolorError> {
+        let v: f32 = self.number()?;
+        if self.peek() == Some(b'%') {
+            self.pos += 1;
+            Ok(v * percent_scale / 100.0)
+        } else {
+            Ok(v)
+        }
+    }
+    /// parse a number which must be in the given range,
+    /// reporting the component if it's outside
+    fn number_in(
+        &mut self,
+        component: &'static str,
+        min: f32,
+        max: f32,
+        percent_scale: f32,
+    ) -> Result<f32, CoolorError> {
+        let v = self.number_or_percentage(percent_scale)?;
+        CoolorError::check(component, v, min..=max)?;
+        Ok(v)
+    }
+    /// parse a percentage of a `hsl()` function, the `%` being
+    /// optional, and return it in `[0,1]`
+    ///
+    /// The division is done in f64 so that the exact percentages
+    /// written by `Display` give back the same f32.
+    fn percentage(&mut self, component: &'static str) -> Result<f32, CoolorError> {
+        let v: f64 = self.number()?;
+        if self.peek() == Some(b'%') {
+            self.pos += 1;
+        }
+        let v = (v / 100.0) as f32;
+        CoolorError::check(component, v, 0.0..=1.0)?;
+        Ok(v)
+    }
+    /// parse the optional alpha part of a `rgb()`, `rgba()`, `hsl()`
+    /// or `hsla()` function, either after a comma or after a slash
+    fn alpha(&mut self) -> Result<Option<f32>, CoolorError> {
+        if self.eat(b',') || self.eat(b'/') {
+            self.number_in("a", 0.0, 1.0, 1.0).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+    /// parse the digits of a hexadecimal color: `rgb`, `rgba`,
+    /// `rrggbb` or `rrggbbaa`
+    fn hex(&mut self) -> Result<(Rgb, Option<f32>), CoolorError> {
+        let start = self.pos;
+        let digits = self.take_while(|b| b.is_ascii_hexdigit());
+        let v = |i: usize| u8::from_str_radix(&digits[i..i + 1], 16).unwrap();
+        let alpha = |a: u8| Some(a as f32 / 255.0);
+        match digits.len() {
+            3 => Ok((Rgb::new(v(0) * 17, v(1) * 17, v(2) * 17), None)),
+            4 => Ok((Rgb::new(v(0) * 17, v(1) * 17, v(2) * 17), alpha(v(3) * 17))),
+            6 => Ok((
+                Rgb::new(v(0) * 16 + v(1), v(2) * 16 + v(3), v(4) * 16 + v(5)),
+                None,
+            )),
+            8 => Ok((
+                Rgb::new(v(0) * 16 + v(1), v(2) * 16 + v(3), v(4) * 16 + v(5)),
+                alpha(v(6) * 16 + v(7)),
+            )),
+            _ => {
+                self.pos = start + digits.len().min(8);
+                self.err_with(CoolorError::InvalidHex)
+            }
+        }
+    }
+    fn rgb_arguments(&mut self) -> Result<Rgb, CoolorError> {
+        let r = self.number_in("r", 0.0, 255.0, 255.0)?;
+        self.separator();
+        let g = self.number_in("g", 0.0, 255.0, 255.0)?;
+        self.separator();
+        let b = self.number_in("b", 0.0, 255.0, 255.0)?;
+        let c = |v: f32| libm::roundf(v) as u8;
+        Ok(Rgb::new(c(r), c(g), c(b)))
+    }
+    /// parse a hue, in degrees, with an optional `deg` unit,
+    /// and bring it in `[0,360[`
+    fn hue(&mut self) -> Result<f32, CoolorError> {
+        let h: f32 = self.number()?;
+        if self.rest().starts_with("deg") {
+            self.pos += 3;
+        }
+        CoolorError::check("h", h, f32::MIN..=f32::MAX)?;
+        // hues in range are kept as is, as adding 360 would round them
+        if (0.0..360.0).contains(&h) {
+            return Ok(h);
+        }
+        let h = ((h % 360.0) + 360.0) % 360.0;
+        Ok(if h < 360.0 { h } else { 0.0 })
+    }
+    /// parse the 3 space separated numbers of a `lab()`, `lch()`,
+    /// `oklab()` or `oklch()` function, each with the scale
+    /// of a percentage
+    fn triplet(&mut self, scales: [f32; 3], hue: bool) -> Result<[f32; 3], CoolorError> {
+        let a = self.number_or_percentage(scales[0])?;
+        let b = self.number_or_percentage(scales[1])?;
+        let c = if hue {
+            self.hue()?
+        } else {
+            self.number_or_percentage(scales[2])?
+        };
+        Ok([a, b, c])
+    }
+    fn hsl_arguments(&mut self) -> Result<Hsl, CoolorError> {
+        let h = self.hue()?;
+        self.separator();
+        let s = self.percentage("s")?;
+        self.separator();
+        let l = self.percentage("l")?;
+        Hsl::checked(h, s, l)
+    }
+    /// parse a color, and its alpha if there's one
+    fn color(&mut self) -> Result<(Color, Option<f32>), CoolorError> {
+        self.skip_whitespace();
+        if self.eat(b'#') {
+            return self.hex().map(|(rgb, alpha)| (Color::Rgb(rgb), alpha));
+        }
+        let start = self.pos;
+        let name = self.identifier();
+        if !self.eat(b'(') {
+            return match Color::from_name(name) {
+                Some(color) => Ok((color, None)),
+                None => {
+                    self.pos = start;
+                    self.err_with(CoolorError::UnknownName)
+                }
+            };
+        }
+        let mut alpha = None;
+        let color = if name.eq_ignore_ascii_case("rgb") || name.eq_ignore_ascii_case("rgba") {
+            let rgb = self.rgb_arguments()?;
+            alpha = self.alpha()?;
+            Color::Rgb(rgb)
+        } else if name.eq_ignore_ascii_case("hsl") || name.eq_ignore_ascii_case("hsla") {
+            let hsl = self.hsl_arguments()?;
+            alpha = self.alpha()?;
+            Color::Hsl(hsl)
+        } else if name.eq_ignore_ascii_case("lab") {
+            let [l, a, b] = self.triplet([100.0, 125.0, 125.0], false)?;
+            Color::Lab(Lab::checked(l, a, b)?)
+        } else if name.eq_ignore_ascii_case("lch") {
+            let [l, c, h] = self.triplet([100.0, 150.0, 0.0], true)?;
+            Color::Lch(Lch::checked(l, c, h)?)
+        } else if name.eq_ignore_ascii_case("oklab") {
+            let [l, a, b] = self.triplet([1.0, 0.4, 0.4], false)?;
+            Color::Oklab(Oklab::checked(l, a, b)?)
+        } else if name.eq_ignore_ascii_case("oklch") {
+            let [l, c, h] = self.triplet([1.0, 0.4, 0.0], true)?;
+            Color::Oklch(Oklch::checked(l, c, h)?)
+        } else if name.eq_ignore_ascii_case("ansi") {
+            self.skip_whitespace();
+            let code = self.take_while(|b| b.is_ascii_digit());
+            match code.parse() {
+                Ok(code) => Color::Ansi(AnsiColor::new(code)),
+                Err(_) => {
+                    self.pos -= code.len();
+                    return self.err();
+                }
+            }
+        } else {
+            self.pos = start;
+            return self.err_with(CoolorError::UnknownName);
+        };
+        self.expect(b')')?;
+        Ok((color, alpha))
+    }
+}
+
+impl FromStr for Color {
+    type Err = CoolorError;
+    /// Parse a color in one of the following syntaxes:
+    ///
+    /// - `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`
+    /// - `rgb(r, g, b)` or `rgb(r g b)`, with numbers in `[0,255]` or percentages
+    /// - `hsl(h, s%, l%)` or `hsl(h s% l%)`, with a hue in degrees
+    /// - `lab(l a b)`, `lch(l c h)`, `oklab(l a b)` and `oklch(l c h)`,
+    ///   following the CSS Color 4 syntax
+    /// - `ansi(n)`, with an ANSI code in `[0,255]`
+    /// - a CSS or X11 color name, eg `rebeccapurple` (see [Color::from_name])
+    ///
+    /// Colors with an alpha part, like `rgba()`, `hsla()` or `rgb(r g b / a)`,
+    /// are accepted but the alpha is ignored: parse a [Rgba] or a [Hsla]
+    /// to keep it.
+    ///
+    /// This is also the syntax written by the `Display` implementations.
+    ///
+    /// A number outside of the range of its component gives a
+    /// [CoolorError::OutOfRange] error. On other failures, the error
+    /// contains the byte position of the problem.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s).map(|(color, _)| color)
+    }
+}
+
+impl FromStr for Rgb {
+    type Err = CoolorError;
+    /// Parse a color in any of the syntaxes accepted for [Color]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<Color>().map(Color::rgb)
+    }
+}
+
+impl FromStr for Hsl {
+    type Err = CoolorError;
+    /// Parse a color in any of the syntaxes accepted for [Color]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<Color>().map(Color::hsl)
+    }
+}
+
+impl FromStr for Rgba {
+    type Err = CoolorError;
+    /// Parse a color in any of the syntaxes accepted for [Color],
+    /// keeping the alpha part, the color being opaque when there's none
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (color, alpha) = parse(s)?;
+        Ok(color.rgb().with_alpha(alpha.unwrap_or(1.0)))
+    }
+}
+
+impl FromStr for Hsla {
+    type Err = CoolorError;
+    /// Parse a color in any of the syntaxes accepted for [Color],
+    /// keeping the alpha part, the color being opaque when there's none
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (color, alpha) = parse(s)?;
+        let Hsl { h, s, l } = color.hsl();
+        Hsla::checked(h, s, l, alpha.unwrap_or(1.0))
+    }
+}
+
+fn parse(s: &str) -> Result<(Color, Option<f32>), CoolorError> {
+    let mut parser = Parser::new(s);
+    let color = parser.color()?;
+    parser.end()?;
+    Ok(color)
+}
+
+#[test]
+fn test_parse() {
+    let rgb = |s: &str| s.parse::<Rgb>().unwrap();
+    assert_eq!(rgb("#f80"), Rgb::new(255, 136, 0));
+    assert_eq!(rgb(" #FF8800 "), Rgb::new(255, 136, 0));
+    assert_eq!(rgb("rgb(255, 136, 0)"), Rgb::new(255, 136, 0));
+    assert_eq!(rgb("rgb(100% 53.333% 0%)"), Rgb::new(255, 136, 0));
+    assert_eq!(rgb("rgba(255,136,0,0.5)"), Rgb::new(255, 136, 0));
+    assert_eq!(rgb("rgb(255 136 0 / 50%)"), Rgb::new(255, 136, 0));
+    assert_eq!(rgb("Orange"), Rgb::new(255, 165, 0));
+    assert_eq!(rgb("ansi(196)"), Rgb::new(255, 0, 0));
+    assert_eq!(rgb("#ff880080"), Rgb::new(255, 136, 0));
+    let hsl = "hsl(120deg, 50%, 25%)".parse::<Hsl>().unwrap();
+    assert_eq!(hsl, Hsl::new(120.0, 0.5, 0.25));
+    assert!(matches!("ansi(12)".parse(), Ok(Color::Ansi(AnsiColor { code: 12 }))));
+}
+#[test]
+fn test_parse_errors() {
+    let err = |s: &str| s.parse::<Color>().unwrap_err();
+    assert_eq!(err("#12345"), CoolorError::InvalidHex(6));
+    assert_eq!(err("#12345z"), CoolorError::InvalidHex(6));
+    assert_eq!(err("#1234567"), CoolorError::InvalidHex(8));
+    assert_eq!(err("#123456789"), CoolorError::InvalidHex(9));
+    assert_eq!(
+        err("rgb(12, 300, 0)"),
+        CoolorError::OutOfRange {
+            component: "g",
+            value: 300.0
+        }
+    );
+    assert_eq!(
+        err("hsl(12 30% 101%)"),
+        CoolorError::OutOfRange {
+            component: "l",
+            value: 1.01
+        }
+    );
+    assert!(matches!(
+        err("rgba(12, 30, 0, 1.5)"),
+        CoolorError::OutOfRange { component: "a", .. }
+    ));
+    assert!(matches!(
+        err("hsl(1000000000000000000000000000000000000000 30% 0%)"),
+        CoolorError::OutOfRange { component: "h", .. }
+    ));
+    assert_eq!(
+        err("lab(150 0 0)"),
+        CoolorError::OutOfRange {
+            component: "l",
+            value: 150.0
+        }
+    );
+    assert_eq!(
+        err("oklch(0.5 -1 30)"),
+        CoolorError::OutOfRange {
+            component: "c",
+            value: -1.0
+        }
+    );
+    assert!(matches!(
+        err("oklab(120% 0 0)"),
+        CoolorError::OutOfRange { component: "l", .. }
+    ));
+    // hues are brought in [0,360[
+    let lch: Color = "lch(50 30 400)".parse().unwrap();
+    assert_eq!(lch, Color::Lch(Lch::new(50.0, 30.0, 40.0)));
+    assert_eq!(err("rgb(12, 30, 0"), CoolorError::InvalidColorString(13));
+    assert_eq!(err("  reddish"), CoolorError::UnknownName(2));
+    assert_eq!(err("rgbb(1, 2, 3)"), CoolorError::UnknownName(0));
+    assert_eq!(err("hsl(12, 30%, 0%) x"), CoolorError::InvalidColorString(17));
+    assert_eq!(err("ansi(3.5)"), CoolorError::InvalidColorString(6));
+    assert_eq!(err("ansi(256)"), CoolorError::InvalidColorString(5));
+}
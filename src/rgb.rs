@@ -21,11 +21,17 @@ impl Rgb {
     }
     #[inline]
     pub fn nearest_ansi_in_range(self, min: u8, max: u8) -> AnsiColor {
+        self.nearest_ansi_in_range_with::<RedmeanDistance>(min, max)
+    }
+    /// Return the nearest ANSI color whose code is in `[min,max]`,
+    /// according to the given metric
+    #[inline]
+    pub fn nearest_ansi_in_range_with<M: ColorDistance>(self, min: u8, max: u8) -> AnsiColor {
         let mut best = AnsiColor { code: min };
-        let mut smallest_distance: f32 = self.distance_to(best.to_rgb());
+        let mut smallest_distance: f32 = M::distance(self, best.to_rgb());
         for code in min+1..=max {
             let color = AnsiColor { code };
-            let distance = self.distance_to(color.to_rgb());
+            let distance = M::distance(self, color.to_rgb());
             if distance < smallest_distance {
                 best = color;
                 smallest_distance = distance;
@@ -37,29 +43,80 @@ impl Rgb {
     ///
     /// The ansi->rgb->ansi round trip is guaranteed to
     /// always fall on the first color.
+    ///
+    /// This is done in constant time: once the red level of the
+    /// 6x6x6 ANSI cube is chosen, the redmean distance is separable
+    /// and the green and blue levels are just the nearest ones.
+    /// The result is the one of a search of the smallest
+    /// [Rgb::distance_to] in the cube slice of the chosen red level.
     pub fn to_ansi(self) -> AnsiColor {
         if self.r == self.g && self.g == self.b {
-            AnsiColor { code: GREY_TO_ANSI[self.r as usize] }
-        } else if self.r < 108 {
+            return AnsiColor { code: GREY_TO_ANSI[self.r as usize] };
+        }
+        let r = if self.r < 108 {
             if self.r < 41 {
-                self.nearest_ansi_in_range(17, 51)
+                0
             } else {
-                self.nearest_ansi_in_range(52, 87)
+                1
             }
         } else if self.r < 195 {
             if self.r < 151 {
-                self.nearest_ansi_in_range(88, 123)
+                2
             } else {
-                self.nearest_ansi_in_range(124, 159)
+                3
             }
         } else {
             if self.r < 235 {
-                self.nearest_ansi_in_range(160, 195)
+                4
             } else {
-                self.nearest_ansi_in_range(196, 230)
+                5
             }
+        };
+        let code = 16 + 36 * r + 6 * cube_level(self.g) + cube_level(self.b);
+        match code {
+            // black and white are excluded from the cube slices, the
+            // best alternative is one of their neighbours
+            16 => self.nearest_of(17, 22),
+            231 => self.nearest_of(225, 230),
+            _ => AnsiColor { code },
         }
     }
+    /// Return the nearest of two ANSI colors, the first one on equality
+    fn nearest_of(self, a: u8, b: u8) -> AnsiColor {
+        let (a, b) = (AnsiColor { code: a }, AnsiColor { code: b });
+        if self.distance_to(b.to_rgb()) < self.distance_to(a.to_rgb()) {
+            b
+        } else {
+            a
+        }
+    }
+    /// Return the nearest color of the 16 colors ANSI palette
+    pub fn to_ansi16(self) -> Ansi16 {
+        Ansi16::from_code(self.nearest_ansi_in_range(0, 15).code).unwrap()
+    }
+    /// Return the nearest color of the 8 basic ANSI colors
+    pub fn to_ansi8(self) -> Ansi16 {
+        Ansi16::from_code(self.nearest_ansi_in_range(0, 7).code).unwrap()
+    }
+    /// Return the ANSI code whose color, in the given palette, is the nearest
+    pub fn to_ansi_in(self, palette: &Palette) -> AnsiColor {
+        palette.nearest_ansi(self)
+    }
+    /// Return the color of the 16 colors ANSI palette which, as
+    /// defined in the given palette, is the nearest
+    pub fn to_ansi16_in(self, palette: &Palette) -> Ansi16 {
+        palette.nearest_ansi16(self)
+    }
+    /// Return the nearest ANSI color according to the given metric
+    ///
+    /// All 240 colors of the 16..=255 range are tried, so this is slower
+    /// than [Rgb::to_ansi], even with the same [RedmeanDistance] metric.
+    pub fn to_ansi_with<M: ColorDistance>(self) -> AnsiColor {
+        M::nearest_ansi(self)
+    }
+    /// Interpolate between two colors by averaging their gamma-encoded
+    /// components, which is fast but gives too dark intermediate colors.
+    /// See [Rgb::mix_linear] for a physically correct mix.
     pub fn mix(c1: Self, w1: f32, c2: Self, w2: f32) -> Self {
         debug_assert!(w1 + w2 > 0.0);
         let (r1, g1, b1) = c1.parts();
@@ -69,6 +126,19 @@ impl Rgb {
         let b = (w1 * b1 + w2 * b2) / (w1 + w2);
         (r, g, b).into()
     }
+    /// Interpolate between two colors in linear light, as
+    /// would do the mix of two light sources
+    pub fn mix_linear(c1: Self, w1: f32, c2: Self, w2: f32) -> Self {
+        LinearRgb::mix(c1.to_linear(), w1, c2.to_linear(), w2).to_rgb()
+    }
+    /// Decode the color into linear light
+    pub fn to_linear(self) -> LinearRgb {
+        self.into()
+    }
+    /// Encode a linear light color, clamping the components outside `[0,1]`
+    pub fn from_linear(linear: LinearRgb) -> Self {
+        linear.to_rgb()
+    }
     #[allow(clippy::float_cmp)]
     pub fn to_hsl(self) -> Hsl {
         let (r, g, b) = (self.rp(), self.gp(), self.bp());
@@ -98,20 +168,34 @@ impl Rgb {
         } else {
             (max - min) / (2.0 - 2.0 * l)
         };
+        // rounding errors may push the saturation slightly above 1
+        let s = s.clamp(0.0, 1.0);
 
         Hsl { h, s, l }
     }
+    pub fn to_lab(self) -> Lab {
+        self.into()
+    }
+    pub fn to_lch(self) -> Lch {
+        self.to_lab().to_lch()
+    }
+    pub fn to_oklab(self) -> Oklab {
+        self.into()
+    }
+    pub fn to_oklch(self) -> Oklch {
+        self.to_oklab().to_oklch()
+    }
     /// red part in `[0,1]`
     pub fn rp(self) -> f32 {
-        self.r as f32 / 256f32
+        self.r as f32 / 255f32
     }
     /// green part in `[0,1]`
     pub fn gp(self) -> f32 {
-        self.g as f32 / 256f32
+        self.g as f32 / 255f32
     }
     /// blue part in `[0,1]`
     pub fn bp(self) -> f32 {
-        self.b as f32 / 256f32
+        self.b as f32 / 255f32
     }
     pub fn parts(self) -> (f32, f32, f32) {
         (self.rp(), self.gp(), self.bp())
@@ -120,6 +204,9 @@ impl Rgb {
     /// going from 0 (black) to 1 (white).
     ///
     /// Reference: <https://en.wikipedia.org/wiki/Luma_(video)>
+    ///
+    /// This isn't the relative luminance used for accessibility
+    /// contrast ratios, which is given by [Rgb::relative_luminance].
     pub fn luma(self) -> f32 {
         0.2627 * self.rp() + 0.6780 * self.gp() + 0.0593 * self.bp()
     }
@@ -137,12 +224,31 @@ impl Rgb {
     }
 }
 
+/// Convert a component in `[0,1]` to the nearest u8
 pub fn r255(v: f32) -> u8 {
-    (v * 255.0) as u8
+    libm::roundf(v * 255.0) as u8
+}
+
+/// Return the index, in `[0,5]`, of the nearest level
+/// (0, 95, 135, 175, 215, 255) of the ANSI color cube
+const fn cube_level(v: u8) -> u8 {
+    if v < 48 {
+        0
+    } else if v <= 115 {
+        1
+    } else if v <= 155 {
+        2
+    } else if v <= 195 {
+        3
+    } else if v <= 235 {
+        4
+    } else {
+        5
+    }
 }
 
 impl From<(f32, f32, f32)> for Rgb {
-    /// Convert from a (r,g,b) float tupples with components in [0,1[
+    /// Convert from a (r,g,b) float tupples with components in `[0,1]`
     fn from(c: (f32, f32, f32)) -> Self {
         debug_assert!(c.0 <= 1.0);
         debug_assert!(c.1 <= 1.0);
@@ -151,6 +257,37 @@ impl From<(f32, f32, f32)> for Rgb {
     }
 }
 
+impl From<LinearRgb> for Rgb {
+    fn from(linear: LinearRgb) -> Self {
+        linear.to_rgb()
+    }
+}
+impl From<Ansi16> for Rgb {
+    fn from(ansi16: Ansi16) -> Self {
+        ansi16.to_rgb()
+    }
+}
+impl From<Lab> for Rgb {
+    fn from(lab: Lab) -> Self {
+        lab.to_rgb()
+    }
+}
+impl From<Lch> for Rgb {
+    fn from(lch: Lch) -> Self {
+        lch.to_rgb()
+    }
+}
+impl From<Oklab> for Rgb {
+    fn from(oklab: Oklab) -> Self {
+        oklab.to_rgb()
+    }
+}
+impl From<Oklch> for Rgb {
+    fn from(oklch: Oklch) -> Self {
+        oklch.to_rgb()
+    }
+}
+
 pub const GREY_TO_ANSI: &[u8] = &[
     16,
     16,
@@ -410,4 +547,52 @@ pub const GREY_TO_ANSI: &[u8] = &[
     231,
 ];
 
-
+#[cfg(test)]
+fn check_to_ansi_is_nearest_in_slice(step: usize) {
+    for r in 0..=255 {
+        let (min, max) = match r {
+            0..=40 => (17, 51),
+            41..=107 => (52, 87),
+            108..=150 => (88, 123),
+            151..=194 => (124, 159),
+            195..=234 => (160, 195),
+            _ => (196, 230),
+        };
+        for g in (0..=255).step_by(step) {
+            for b in 0..=255 {
+                let rgb = Rgb::new(r, g, b);
+                if !rgb.is_grey() {
+                    assert_eq!(rgb.to_ansi(), rgb.nearest_ansi_in_range(min, max));
+                }
+            }
+        }
+    }
+}
+/// check the direct computation of the nearest ANSI color gives
+/// the same result than a search in the relevant slice of the cube
+#[test]
+fn test_to_ansi_is_nearest_in_slice() {
+    check_to_ansi_is_nearest_in_slice(17);
+}
+/// same check, on all RGB colors
+/// (run with `cargo test --release -- --ignored`)
+#[test]
+#[ignore]
+fn test_to_ansi_is_nearest_in_slice_exhaustive() {
+    check_to_ansi_is_nearest_in_slice(1);
+}
+/// check the HSL components stay in their ranges, on a sweep of RGB colors
+#[test]
+fn test_to_hsl_ranges() {
+    for r in (0..=255).step_by(3) {
+        for g in (0..=255).step_by(3) {
+            for b in 0..=255 {
+                let hsl = Rgb::new(r, g, b).to_hsl();
+                assert!((0.0..360.0).contains(&hsl.h), "h of {r},{g},{b}");
+                assert!((0.0..=1.0).contains(&hsl.s), "s of {r},{g},{b}");
+                assert!((0.0..=1.0).contains(&hsl.l), "l of {r},{g},{b}");
+            }
+        }
+    }
+    assert!("#0103ff".parse::<Hsla>().is_ok());
+}
@@ -0,0 +1,80 @@
+use crate::*;
+
+/// RGB color with an alpha channel, with u8 components
+///
+/// An alpha of 0 is fully transparent, an alpha of 255 is opaque.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rgba {
+    /// red
+    pub r: u8,
+    /// green
+    pub g: u8,
+    /// blue
+    pub b: u8,
+    /// alpha (opacity)
+    pub a: u8,
+}
+
+impl Rgba {
+    /// Create a new RGBA color from its components
+    pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+    /// The color without its transparency
+    pub const fn rgb(self) -> Rgb {
+        Rgb::new(self.r, self.g, self.b)
+    }
+    /// opacity in `[0,1]`
+    pub fn alpha(self) -> f32 {
+        self.a as f32 / 255.0
+    }
+    pub fn to_hsla(self) -> Hsla {
+        self.rgb().to_hsl().with_alpha(self.alpha())
+    }
+    /// Compute the color seen when this color is drawn over
+    /// an opaque background (Porter-Duff "source over")
+    ///
+    /// Blending is done in linear light, as in the physical world.
+    pub fn over<B: Into<Rgb>>(self, background: B) -> Rgb {
+        let bg: Rgb = background.into();
+        let a = self.alpha();
+        LinearRgb::mix(self.rgb().to_linear(), a, bg.to_linear(), 1.0 - a).to_rgb()
+    }
+    /// Return the nearest ANSI color of the color seen when this color
+    /// is drawn over an opaque background
+    pub fn over_ansi<B: Into<Rgb>>(self, background: B) -> AnsiColor {
+        self.over(background).to_ansi()
+    }
+}
+
+impl Rgb {
+    /// Return the same color with the given opacity, in `[0,1]`
+    pub fn with_alpha(self, alpha: f32) -> Rgba {
+        debug_assert!((0.0..=1.0).contains(&alpha));
+        let a = libm::roundf(alpha.clamp(0.0, 1.0) * 255.0) as u8;
+        Rgba::new(self.r, self.g, self.b, a)
+    }
+}
+
+impl From<Rgb> for Rgba {
+    /// Convert to a fully opaque color
+    fn from(rgb: Rgb) -> Self {
+        Rgba::new(rgb.r, rgb.g, rgb.b, 255)
+    }
+}
+impl From<Hsla> for Rgba {
+    fn from(hsla: Hsla) -> Self {
+        hsla.to_rgba()
+    }
+}
+
+#[test]
+fn test_over() {
+    let black = Rgb::new(0, 0, 0);
+    let red = Rgb::new(200, 30, 10);
+    assert_eq!(red.with_alpha(1.0).over(black), red);
+    assert_eq!(red.with_alpha(0.0).over(black), black);
+    // half white over black is half the light, which isn't 128
+    assert_eq!(Rgb::new(255, 255, 255).with_alpha(0.5).over(black), Rgb::new(188, 188, 188));
+    assert_eq!(red.with_alpha(1.0).over_ansi(black), red.to_ansi());
+}
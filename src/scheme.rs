@@ -0,0 +1,396 @@
+use {
+    crate::*,
+    core::ops::RangeInclusive,
+};
+
+/// A classic color harmony, defined by rotations of the hue of a seed color
+///
+/// Hues are rotated in [Oklch], so that the lightness and
+/// the chroma are perceptually kept.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Harmony {
+    /// the seed and its opposite
+    Complementary,
+    /// the seed and its neighbours, at 30°
+    Analogous,
+    /// 3 colors evenly spaced on the hue circle
+    Triadic,
+    /// 2 pairs of complementary colors, forming a rectangle
+    Tetradic,
+    /// the seed and the neighbours, at 30°, of its opposite
+    SplitComplementary,
+}
+
+impl Harmony {
+    /// Return the hue rotations, in degrees, the first one
+    /// being the one of the seed
+    pub const fn hue_rotations(self) -> &'static [f32] {
+        match self {
+            Self::Complementary => &[0.0, 180.0],
+            Self::Analogous => &[0.0, 30.0, 330.0],
+            Self::Triadic => &[0.0, 120.0, 240.0],
+            Self::Tetradic => &[0.0, 60.0, 180.0, 240.0],
+            Self::SplitComplementary => &[0.0, 150.0, 210.0],
+        }
+    }
+    /// Build the palette of this harmony for the seed color,
+    /// which is the first color of the palette
+    ///
+    /// Fails with [CoolorError::NotDistinct] when no variant of a color
+    /// differs enough from the previous ones.
+    pub fn palette<C: Into<Color>>(
+        self,
+        seed: C,
+        options: HarmonyOptions,
+    ) -> Result<HarmonyPalette, CoolorError> {
+        match self.best_palette(seed.into(), options) {
+            (palette, true) => Ok(palette),
+            (_, false) => Err(CoolorError::NotDistinct(options.min_delta_e)),
+        }
+    }
+    /// Build the palette, and return whether all colors
+    /// differ by at least the required difference
+    fn best_palette(self, seed: Color, options: HarmonyOptions) -> (HarmonyPalette, bool) {
+        let seed_oklch = seed.oklch();
+        let mut palette = HarmonyPalette {
+            colors: [seed; 4],
+            len: 0,
+        };
+        let mut success = true;
+        for &rotation in self.hue_rotations() {
+            let color = if rotation == 0.0 {
+                seed
+            } else {
+                let h = (seed_oklch.h + rotation) % 360.0;
+                Color::Rgb(Oklch { h, ..seed_oklch }.with_chroma_in_gamut().to_rgb())
+            };
+            let (color, distinct) = palette.distinct_variant(color, options);
+            success &= distinct;
+            palette.colors[palette.len] = color;
+            palette.len += 1;
+        }
+        (palette, success)
+    }
+}
+
+/// Constraints on the colors of a harmony palette
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct HarmonyOptions {
+    /// whether all colors must be ANSI colors
+    pub ansi: bool,
+    /// minimal [DeltaE::Ciede2000] difference between any two colors
+    pub min_delta_e: f32,
+}
+
+/// The colors of a [Harmony], the seed being the first one
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HarmonyPalette {
+    colors: [Color; 4],
+    len: usize,
+}
+
+impl HarmonyPalette {
+    pub fn as_slice(&self) -> &[Color] {
+        &self.colors[..self.len]
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// Return the smallest CIEDE2000 difference between
+    /// the given color and the colors already in the palette
+    fn min_delta_e(&self, color: Color) -> f32 {
+        self.as_slice()
+            .iter()
+            .map(|&c| c.delta_e(color, DeltaE::Ciede2000))
+            .fold(f32::MAX, f32::min)
+    }
+    /// Return the color, converted to ANSI if required, with its lightness
+    /// changed as little as possible so that it differs enough from the
+    /// colors already in the palette, and whether it does.
+    ///
+    /// If no variant is different enough, the most different one is returned.
+    fn distinct_variant(&self, color: Color, options: HarmonyOptions) -> (Color, bool) {
+        let constrained = |color: Color| {
+            if options.ansi {
+                Color::Ansi(color.ansi())
+            } else {
+                color
+            }
+        };
+        let mut best = constrained(color);
+        let mut best_delta_e = self.min_delta_e(best);
+        if best_delta_e >= options.min_delta_e {
+            return (best, true);
+        }
+        let oklch = color.oklch();
+        for step in 1..=20 {
+            for sign in [-1.0, 1.0] {
+                let l = oklch.l + sign * step as f32 * 0.05;
+                if !(0.0..=1.0).contains(&l) {
+                    continue;
+                }
+                let variant = Oklch { l, ..oklch }.with_chroma_in_gamut().to_rgb();
+                let variant = constrained(Color::Rgb(variant));
+                let delta_e = self.min_delta_e(variant);
+                if delta_e >= options.min_delta_e {
+                    return (variant, true);
+                }
+                if delta_e > best_delta_e {
+                    best = variant;
+                    best_delta_e = delta_e;
+                }
+            }
+        }
+        (best, false)
+    }
+}
+
+impl<'p> IntoIterator for &'p HarmonyPalette {
+    type Item = &'p Color;
+    type IntoIter = core::slice::Iter<'p, Color>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice().iter()
+    }
+}
+
+impl Color {
+    /// Build the palette of the given harmony, with this color as seed
+    /// and no constraint
+    pub fn harmony(self, harmony: Harmony) -> HarmonyPalette {
+        harmony.best_palette(self, HarmonyOptions::default()).0
+    }
+}
+
+/// An ordered set of named colors, like a terminal or syntax theme,
+/// with a fixed capacity of `N` colors
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Scheme<'n, const N: usize> {
+    entries: [Option<(&'n str, Color)>; N],
+    len: usize,
+}
+
+impl<'n, const N: usize> Scheme<'n, N> {
+    /// The default tolerance of [Scheme::to_ansi], in CIEDE2000
+    pub const DEFAULT_ANSI_TOLERANCE: f32 = 8.0;
+    pub const fn new() -> Self {
+        Self {
+            entries: [None; N],
+            len: 0,
+        }
+    }
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// Set the color of the given name, replacing the previous
+    /// color if the name is already present, or adding it at the end
+    pub fn set<C: Into<Color>>(&mut self, name: &'n str, color: C) -> Result<(), CoolorError> {
+        let color = color.into();
+        if let Some(entry) = self.entries[..self.len]
+            .iter_mut()
+            .flatten()
+            .find(|(n, _)| *n == name)
+        {
+            entry.1 = color;
+            return Ok(());
+        }
+        if self.len == N {
+            return Err(CoolorError::CapacityExceeded(N));
+        }
+        self.entries[self.len] = Some((name, color));
+        self.len += 1;
+        Ok(())
+    }
+    pub fn get(&self, name: &str) -> Option<Color> {
+        self.iter().find(|(n, _)| *n == name).map(|(_, c)| c)
+    }
+    /// Iterate over the names and colors, in insertion order
+    pub fn iter(&self) -> impl Iterator<Item = (&'n str, Color)> + '_ {
+        self.entries[..self.len].iter().flatten().copied()
+    }
+    /// Shift the colors so that any two of them stay distinguishable
+    /// with the given deficiency, see [daltonize_colors]
+    pub fn daltonize(&mut self, deficiency: Deficiency, min_delta_e: f32) -> bool {
+        let mut colors = [Color::Ansi(AnsiColor::new(0)); N];
+        for (idx, (_, color)) in self.iter().enumerate() {
+            colors[idx] = color;
+        }
+        let success = daltonize_colors(&mut colors[..self.len], deficiency, min_delta_e);
+        for (entry, color) in self.entries[..self.len].iter_mut().zip(colors) {
+            if let Some((_, c)) = entry {
+                *c = color;
+            }
+        }
+        success
+    }
+    /// Return the same scheme with all colors converted to ANSI
+    ///
+    /// Contrary to converting each color with [Color::ansi], distinct colors
+    /// are kept distinct when there's an unused ANSI color close enough,
+    /// that is whose CIEDE2000 difference with the source color is at most
+    /// [Scheme::DEFAULT_ANSI_TOLERANCE].
+    pub fn to_ansi(&self) -> Self {
+        self.to_ansi_with_tolerance(Self::DEFAULT_ANSI_TOLERANCE)
+    }
+    /// Return the same scheme with all colors converted to ANSI,
+    /// distinct colors being kept distinct when there's an unused
+    /// ANSI color whose CIEDE2000 difference with the source color
+    /// is at most `tolerance`.
+    ///
+    /// Colors which are already ANSI are kept. The colors nearest
+    /// to an ANSI color are given that color first.
+    pub fn to_ansi_with_tolerance(&self, tolerance: f32) -> Self {
+        self.map_to_ansi(tolerance, 16..=255, Color::ansi)
+    }
+    /// Return the same scheme with all colors converted to the 16 basic
+    /// ANSI colors, distinct colors being kept distinct when there's an
+    /// unused one close enough, as for [Scheme::to_ansi]
+    ///
+    /// The colors displayed for those codes depend on the terminal,
+    /// so this is based on the [Palette::DEFAULT] palette.
+    pub fn to_ansi16(&self) -> Self {
+        self.map_to_ansi(Self::DEFAULT_ANSI_TOLERANCE, 0..=15, |c| {
+            c.ansi16().to_ansi()
+        })
+    }
+    fn map_to_ansi(
+        &self,
+        tolerance: f32,
+        codes: RangeInclusive<u8>,
+        nearest: fn(Color) -> AnsiColor,
+    ) -> Self {
+        let is_kept =
+            |color: Color| matches!(color, Color::Ansi(ansi) if codes.contains(&ansi.code));
+        // the source color of each ANSI color already given
+        let mut owners: [Option<Rgb>; 256] = [None; 256];
+        let mut mapped = [AnsiColor::new(0); N];
+        let mut order = [0usize; N];
+        let mut nearness = [0f32; N];
+        for (idx, (_, color)) in self.iter().enumerate() {
+            order[idx] = idx;
+            mapped[idx] = nearest(color);
+            nearness[idx] = if is_kept(color) {
+                -1.0 // kept colors are handled first
+            } else {
+                color.delta_e(mapped[idx], DeltaE::Ciede2000)
+            };
+        }
+        let order = &mut order[..self.len];
+        order.sort_unstable_by(|&a, &b| nearness[a].total_cmp(&nearness[b]));
+        for &idx in order.iter() {
+            let Some((_, color)) = self.entries[idx] else {
+                continue;
+            };
+            let rgb = color.rgb();
+            let available = |ansi: AnsiColor| owners[ansi.code as usize].is_none_or(|o| o == rgb);
+            if !is_kept(color) && !available(mapped[idx]) {
+                let lab = rgb.to_lab();
+                let mut alternative: Option<(f32, AnsiColor)> = None;
+                for code in codes.clone() {
+                    let ansi = AnsiColor::new(code);
+                    if !available(ansi) {
+                        continue;
+                    }
+                    let delta_e = lab.delta_e_2000(ansi.to_rgb().to_lab());
+                    if delta_e <= tolerance && alternative.is_none_or(|(d, _)| delta_e < d) {
+                        alternative = Some((delta_e, ansi));
+                    }
+                }
+                if let Some((_, ansi)) = alternative {
+                    mapped[idx] = ansi;
+                }
+            }
+            owners[mapped[idx].code as usize].get_or_insert(rgb);
+        }
+        let mut scheme = *self;
+        for (idx, entry) in scheme.entries[..self.len].iter_mut().enumerate() {
+            if let Some((_, color)) = entry {
+                *color = Color::Ansi(mapped[idx]);
+            }
+        }
+        scheme
+    }
+}
+
+impl<const N: usize> Default for Scheme<'_, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[test]
+fn test_harmony_hues() {
+    let seed = Rgb::new(200, 60, 40);
+    let seed_h = seed.to_oklch().h;
+    let palette = Color::Rgb(seed).harmony(Harmony::Triadic);
+    assert_eq!(palette.len(), 3);
+    assert_eq!(palette.as_slice()[0], Color::Rgb(seed));
+    for (color, rotation) in palette.into_iter().zip(Harmony::Triadic.hue_rotations()) {
+        let h = color.oklch().h;
+        let delta = ((h - seed_h - rotation) % 360.0 + 360.0) % 360.0;
+        assert!(!(2.0..=358.0).contains(&delta));
+    }
+}
+#[test]
+fn test_harmony_options() {
+    let harmonies = [
+        Harmony::Complementary,
+        Harmony::Analogous,
+        Harmony::Triadic,
+        Harmony::Tetradic,
+        Harmony::SplitComplementary,
+    ];
+    let options = HarmonyOptions {
+        ansi: true,
+        min_delta_e: 10.0,
+    };
+    for code in (16..=255).step_by(7) {
+        for harmony in harmonies {
+            let palette = harmony.palette(AnsiColor::new(code), options).unwrap();
+            assert_eq!(palette.len(), harmony.hue_rotations().len());
+            let colors = palette.as_slice();
+            for (i, a) in colors.iter().enumerate() {
+                assert!(matches!(a, Color::Ansi(_)));
+                for b in &colors[i + 1..] {
+                    assert!(a.delta_e(*b, DeltaE::Ciede2000) >= 10.0);
+                }
+            }
+        }
+    }
+    // 4 ANSI colors can't differ that much
+    let options = HarmonyOptions {
+        ansi: true,
+        min_delta_e: 90.0,
+    };
+    assert_eq!(
+        Harmony::Tetradic.palette(AnsiColor::new(244), options),
+        Err(CoolorError::NotDistinct(90.0)),
+    );
+}
+#[test]
+fn test_scheme_to_ansi() {
+    let mut scheme: Scheme<4> = Scheme::new();
+    scheme.set("comment", Rgb::new(0, 95, 0)).unwrap();
+    scheme.set("border", Rgb::new(103, 103, 103)).unwrap();
+    scheme.set("selection", AnsiColor::new(242)).unwrap();
+    scheme.set("comment", Rgb::new(101, 101, 101)).unwrap();
+    scheme.set("gutter", Rgb::new(101, 101, 101)).unwrap();
+    assert_eq!(scheme.len(), 4);
+    assert!(scheme.set("info", Rgb::new(0, 0, 255)).is_err());
+    assert!(scheme.iter().all(|(_, c)| c.ansi().code == 242));
+    let ansi_scheme = scheme.to_ansi();
+    let code = |name| ansi_scheme.get(name).unwrap().ansi().code;
+    assert_eq!(code("selection"), 242);
+    assert_ne!(code("comment"), 242);
+    assert_ne!(code("border"), 242);
+    assert_ne!(code("comment"), code("border"));
+    assert_eq!(code("comment"), code("gutter"));
+    // with no tolerance, colors collapse
+    let ansi_scheme = scheme.to_ansi_with_tolerance(0.0);
+    assert!(ansi_scheme.iter().all(|(_, c)| c.ansi().code == 242));
+}
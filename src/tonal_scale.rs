@@ -0,0 +1,111 @@
+use crate::*;
+
+/// A lightness ramp of a color, like the 50 to 950 shades of
+/// a Tailwind palette or the tones of a Material palette
+///
+/// Tones are evenly spaced in [Oklch] lightness and keep the hue
+/// and chroma of the seed, the chroma being reduced when the tone
+/// would be out of the sRGB gamut.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TonalScale {
+    pub seed: Oklch,
+    /// OKLCh lightness of the lightest tone
+    pub lightest: f32,
+    /// OKLCh lightness of the darkest tone
+    pub darkest: f32,
+}
+
+impl TonalScale {
+    /// The steps of a Tailwind palette
+    pub const TAILWIND_STEPS: [u16; 11] = [50, 100, 200, 300, 400, 500, 600, 700, 800, 900, 950];
+    /// Build a scale going from a lightness of 0.97 to 0.27,
+    /// which is about the range of Tailwind palettes
+    pub fn new<C: Into<Color>>(seed: C) -> Self {
+        Self {
+            seed: seed.into().oklch(),
+            lightest: 0.97,
+            darkest: 0.27,
+        }
+    }
+    /// Return the same scale with the given OKLCh lightnesses
+    /// for the lightest and darkest tones
+    pub fn with_range(self, lightest: f32, darkest: f32) -> Self {
+        Self {
+            lightest,
+            darkest,
+            ..self
+        }
+    }
+    /// Return the tone at position `t` in `[0,1]`,
+    /// 0 being the lightest tone and 1 the darkest one
+    pub fn tone(&self, t: f32) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+        let l = self.lightest + (self.darkest - self.lightest) * t;
+        Oklch { l, ..self.seed }.with_chroma_in_gamut().to_rgb()
+    }
+    /// Iterate over `n` tones, from the lightest to the darkest
+    pub fn tones(&self, n: usize) -> impl Iterator<Item = Rgb> + '_ {
+        let intervals = n.saturating_sub(1).max(1) as f32;
+        (0..n).map(move |i| self.tone(i as f32 / intervals))
+    }
+    /// Iterate over ANSI colors of `n` tones, from the lightest to the
+    /// darkest, each one being the nearest (in OKLab) color of the
+    /// 16..=255 range which isn't already used, so that all steps
+    /// are distinct
+    ///
+    /// As there are only 240 such colors, there are `min(n, 240)` steps.
+    pub fn ansi_tones(&self, n: usize) -> impl Iterator<Item = AnsiColor> + '_ {
+        let mut used = [false; 256];
+        self.tones(n).map_while(move |rgb| {
+            let oklab = rgb.to_oklab();
+            let (_, code) = (16..=255)
+                .filter(|&code| !used[code as usize])
+                .map(|code| {
+                    let distance = oklab.distance_to(AnsiColor::new(code).to_rgb().to_oklab());
+                    (distance, code)
+                })
+                .min_by(|a, b| a.0.total_cmp(&b.0))?;
+            used[code as usize] = true;
+            Some(AnsiColor::new(code))
+        })
+    }
+    /// Return the tone of a Tailwind step, in `[50,950]`
+    pub fn tailwind_tone(&self, step: u16) -> Rgb {
+        self.tone((step as f32 - 50.0) / 900.0)
+    }
+    /// Return the 11 tones of a Tailwind palette, with their steps
+    pub fn tailwind(&self) -> [(u16, Rgb); 11] {
+        Self::TAILWIND_STEPS.map(|step| (step, self.tailwind_tone(step)))
+    }
+}
+
+impl Color {
+    /// Return the scale of the tones of this color
+    pub fn tonal_scale(self) -> TonalScale {
+        TonalScale::new(self)
+    }
+}
+
+#[test]
+fn test_tonal_scale() {
+    let seed = Rgb::new(59, 130, 246);
+    let scale = Color::Rgb(seed).tonal_scale();
+    let tones = scale.tailwind();
+    assert_eq!(tones[0].0, 50);
+    assert_eq!(tones[10].0, 950);
+    let seed_h = seed.to_oklch().h;
+    for pair in tones.windows(2) {
+        let (l1, l2) = (pair[0].1.to_oklch().l, pair[1].1.to_oklch().l);
+        assert!(l1 > l2);
+        assert!((pair[1].1.to_oklch().h - seed_h).abs() < 3.0);
+    }
+    assert_eq!(scale.tones(7).count(), 7);
+    let ansi_tones: std::vec::Vec<AnsiColor> = scale.ansi_tones(20).collect();
+    assert_eq!(ansi_tones.len(), 20);
+    for (i, ansi) in ansi_tones.iter().enumerate() {
+        assert!(ansi.code >= 16);
+        assert!(!ansi_tones[i + 1..].contains(ansi));
+    }
+    assert_eq!(ansi_tones[0], scale.tone(0.0).to_ansi_with::<OklabDistance>());
+    assert_eq!(scale.ansi_tones(300).count(), 240);
+}